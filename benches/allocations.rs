@@ -0,0 +1,54 @@
+//! Reports the number of heap allocations per item for common adapter
+//! pipelines. Run with `cargo bench --bench allocations`.
+
+#[path = "../tests/common/alloc_counter.rs"]
+mod alloc_counter;
+
+use alloc_counter::{allocations, block_on};
+use async_iterator::{from_iter, Iterator};
+use std::future::ready;
+use std::hint::black_box;
+
+const LEN: u64 = 100_000;
+
+fn report<T>(name: &str, f: impl FnOnce() -> T) {
+    let allocations = allocations(f);
+    println!(
+        "{:<12} {:>8} allocations, {:.4} per item",
+        name,
+        allocations,
+        allocations as f64 / LEN as f64
+    );
+}
+
+fn main() {
+    report("map", || {
+        block_on(
+            from_iter(0..black_box(LEN))
+                .map(|x| ready(x * 2))
+                .fold(0, |acc, x| ready(acc + x)),
+        )
+    });
+    report("filter", || {
+        block_on(
+            from_iter(0..black_box(LEN))
+                .filter(async |x: &u64| x.is_multiple_of(2))
+                .fold(0, |acc, x| ready(acc + x)),
+        )
+    });
+    report("map_filter", || {
+        block_on(
+            from_iter(0..black_box(LEN))
+                .map(|x| async move { x * 3 })
+                .filter(async |x: &u64| x.is_multiple_of(2))
+                .fold(0, |acc, x| ready(acc + x)),
+        )
+    });
+    report("collect", || {
+        block_on(
+            from_iter(0..black_box(LEN))
+                .map(|x| ready(x * 2))
+                .collect::<Vec<_>>(),
+        )
+    });
+}
@@ -0,0 +1,107 @@
+use async_iterator::{from_iter, Iterator};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::future::{ready, Future};
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+const LEN: u64 = 100_000;
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+    }
+}
+
+fn collect(c: &mut Criterion) {
+    c.bench_function("collect", |b| {
+        b.iter(|| block_on(from_iter(0..black_box(LEN)).collect::<Vec<_>>()))
+    });
+}
+
+fn collect_slice(c: &mut Criterion) {
+    let data: Vec<u64> = (0..LEN).collect();
+    c.bench_function("collect_slice", |b| {
+        b.iter(|| {
+            block_on(
+                from_iter(black_box(&data).iter())
+                    .copied()
+                    .collect::<Vec<_>>(),
+            )
+        })
+    });
+    c.bench_function("collect_slice_to_vec", |b| {
+        b.iter(|| from_iter(black_box(&data).iter()).to_vec())
+    });
+}
+
+fn map(c: &mut Criterion) {
+    c.bench_function("map", |b| {
+        b.iter(|| {
+            block_on(
+                from_iter(0..black_box(LEN))
+                    .map(|x| ready(x * 2))
+                    .collect::<Vec<_>>(),
+            )
+        })
+    });
+}
+
+fn filter(c: &mut Criterion) {
+    c.bench_function("filter", |b| {
+        b.iter(|| {
+            block_on(
+                from_iter(0..black_box(LEN))
+                    .filter(async |x: &u64| x.is_multiple_of(2))
+                    .collect::<Vec<_>>(),
+            )
+        })
+    });
+}
+
+fn map_filter(c: &mut Criterion) {
+    c.bench_function("map_filter", |b| {
+        b.iter(|| {
+            block_on(
+                from_iter(0..black_box(LEN))
+                    .map(|x| ready(x * 3))
+                    .filter(async |x: &u64| x.is_multiple_of(2))
+                    .collect::<Vec<_>>(),
+            )
+        })
+    });
+}
+
+fn fold(c: &mut Criterion) {
+    c.bench_function("fold", |b| {
+        b.iter(|| block_on(from_iter(0..black_box(LEN)).fold(0, |acc, x| ready(acc + x))))
+    });
+}
+
+fn for_each(c: &mut Criterion) {
+    c.bench_function("for_each", |b| {
+        b.iter(|| {
+            let mut sum = 0;
+            block_on(from_iter(0..black_box(LEN)).for_each(|x| {
+                sum += x;
+                ready(())
+            }));
+            sum
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    collect,
+    collect_slice,
+    map,
+    filter,
+    map_filter,
+    fold,
+    for_each
+);
+criterion_main!(benches);
@@ -0,0 +1,35 @@
+use crate::Iterator;
+
+/// An iterator that knows its exact length.
+///
+/// Implementors must return an exact `size_hint`, where the lower bound
+/// equals the upper bound. Adapters which never change the number of items,
+/// such as [`Map`](crate::Map) and [`Cloned`](crate::Cloned), are exact when
+/// their source is. Adapters which may drop items, such as
+/// [`Filter`](crate::Filter), are not:
+///
+/// ```compile_fail
+/// use async_iterator::{from_iter, ExactSizeIterator, Iterator};
+///
+/// let iter = from_iter(0..4).filter(async |x: &i32| *x > 1);
+/// iter.len();
+/// ```
+pub trait ExactSizeIterator: Iterator {
+    /// Returns the exact remaining length of the iterator.
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(Some(lower), upper);
+        lower
+    }
+
+    /// Returns `true` if the iterator has no items left.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<I: ExactSizeIterator + ?Sized> ExactSizeIterator for &mut I {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
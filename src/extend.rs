@@ -1,10 +1,14 @@
 use crate::IntoIterator;
 
-#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg(any(
+    feature = "alloc",
+    feature = "std",
+    feature = "async-channel",
+    feature = "futures-channel"
+))]
 use crate::Iterator;
 
 /// Extend a collection with the contents of an iterator.
-
 pub trait Extend<A> {
     /// Extends a collection with the contents of an iterator.
     async fn extend<T>(&mut self, iter: T)
@@ -16,9 +20,458 @@ pub trait Extend<A> {
 impl<T> Extend<T> for std::vec::Vec<T> {
     async fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         let mut iter = iter.into_iter().await;
-        self.reserve(iter.size_hint().1.unwrap_or_default());
+        self.reserve(iter.size_hint().0);
         while let Some(item) = iter.next().await {
             self.push(item);
         }
     }
 }
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T> Extend<T> for std::collections::VecDeque<T> {
+    async fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().await;
+        self.reserve(iter.size_hint().0);
+        while let Some(item) = iter.next().await {
+            self.push_back(item);
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl Extend<char> for std::string::String {
+    async fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().await;
+        self.reserve(iter.size_hint().0);
+        while let Some(c) = iter.next().await {
+            self.push(c);
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<'a> Extend<&'a str> for std::string::String {
+    async fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().await;
+        while let Some(s) = iter.next().await {
+            self.push_str(s);
+        }
+    }
+}
+
+/// The most items a hash collection reserves space for up front when the
+/// iterator's length isn't known exactly.
+#[cfg(feature = "std")]
+pub(crate) const MAX_HASH_RESERVE: usize = 4096;
+
+/// Returns how many items a hash collection should reserve space for.
+///
+/// Exact hints, such as those of an `ExactSizeIterator`, are trusted so the
+/// collection never rehashes while being extended. Other lower bounds are
+/// capped, so a bogus hint can't cause an absurd allocation.
+#[cfg(feature = "std")]
+pub(crate) fn hash_reserve_hint((lower, upper): (usize, Option<usize>)) -> usize {
+    if upper == Some(lower) {
+        lower
+    } else {
+        lower.min(MAX_HASH_RESERVE)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Extend<(K, V)> for std::collections::HashMap<K, V, S>
+where
+    K: Eq + core::hash::Hash,
+    S: core::hash::BuildHasher,
+{
+    async fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().await;
+        self.reserve(hash_reserve_hint(iter.size_hint()));
+        while let Some((key, value)) = iter.next().await {
+            self.insert(key, value);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S> Extend<T> for std::collections::HashSet<T, S>
+where
+    T: Eq + core::hash::Hash,
+    S: core::hash::BuildHasher,
+{
+    async fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().await;
+        self.reserve(hash_reserve_hint(iter.size_hint()));
+        while let Some(item) = iter.next().await {
+            self.insert(item);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: AsRef<std::path::Path>> Extend<P> for std::path::PathBuf {
+    async fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().await;
+        while let Some(path) = iter.next().await {
+            self.push(path);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: AsRef<std::ffi::OsStr>> Extend<S> for std::ffi::OsString {
+    async fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().await;
+        while let Some(s) = iter.next().await {
+            self.push(s);
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<K: Ord, V> Extend<(K, V)> for std::collections::BTreeMap<K, V> {
+    async fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().await;
+        while let Some((key, value)) = iter.next().await {
+            self.insert(key, value);
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T: Ord> Extend<T> for std::collections::BTreeSet<T> {
+    async fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().await;
+        while let Some(item) = iter.next().await {
+            self.insert(item);
+        }
+    }
+}
+
+/// Extend a map with the contents of an iterator, merging the values of
+/// duplicate keys.
+pub trait MergeExtend<K, V> {
+    /// Extends a map with the contents of an iterator, calling `merge` with
+    /// the existing value whenever a key is already present.
+    async fn extend_with<T, F>(&mut self, iter: T, merge: F)
+    where
+        T: IntoIterator<Item = (K, V)>,
+        F: FnMut(&mut V, V);
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> MergeExtend<K, V> for std::collections::HashMap<K, V, S>
+where
+    K: Eq + core::hash::Hash,
+    S: core::hash::BuildHasher,
+{
+    async fn extend_with<I, F>(&mut self, iter: I, mut merge: F)
+    where
+        I: IntoIterator<Item = (K, V)>,
+        F: FnMut(&mut V, V),
+    {
+        use std::collections::hash_map::Entry;
+
+        let mut iter = iter.into_iter().await;
+        while let Some((key, value)) = iter.next().await {
+            match self.entry(key) {
+                Entry::Occupied(mut entry) => merge(entry.get_mut(), value),
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<K: Ord, V> MergeExtend<K, V> for std::collections::BTreeMap<K, V> {
+    async fn extend_with<I, F>(&mut self, iter: I, mut merge: F)
+    where
+        I: IntoIterator<Item = (K, V)>,
+        F: FnMut(&mut V, V),
+    {
+        use std::collections::btree_map::Entry;
+
+        let mut iter = iter.into_iter().await;
+        while let Some((key, value)) = iter.next().await {
+            match self.entry(key) {
+                Entry::Occupied(mut entry) => merge(entry.get_mut(), value),
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+            }
+        }
+    }
+}
+
+/// Sends every item on the channel, waiting for capacity when it is full.
+///
+/// `extend` has no way to report errors, so once the channel is closed it
+/// returns without pulling any further items from the iterator. The item
+/// that failed to send is dropped.
+#[cfg(feature = "async-channel")]
+impl<T> Extend<T> for async_channel::Sender<T> {
+    async fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().await;
+        while let Some(item) = iter.next().await {
+            if self.send(item).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// Sends every item on the channel, waiting for capacity when it is full.
+///
+/// `extend` has no way to report errors, so once the receiver is dropped it
+/// returns without pulling any further items from the iterator. The item
+/// that failed to send is dropped.
+#[cfg(feature = "futures-channel")]
+impl<T> Extend<T> for futures_channel::mpsc::Sender<T> {
+    async fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        use core::future::poll_fn;
+
+        let mut iter = iter.into_iter().await;
+        while let Some(item) = iter.next().await {
+            if poll_fn(|cx| self.poll_ready(cx)).await.is_err() {
+                return;
+            }
+            if self.start_send(item).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// Sends every item on the channel.
+///
+/// `extend` has no way to report errors, so once the receiver is dropped it
+/// returns without pulling any further items from the iterator. The item
+/// that failed to send is dropped.
+#[cfg(feature = "futures-channel")]
+impl<T> Extend<T> for futures_channel::mpsc::UnboundedSender<T> {
+    async fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().await;
+        while let Some(item) = iter.next().await {
+            if self.unbounded_send(item).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+impl<A, E: Extend<A> + ?Sized> Extend<A> for &mut E {
+    async fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        (**self).extend(iter).await
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, E: Extend<A> + ?Sized> Extend<A> for std::boxed::Box<E> {
+    async fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        (**self).extend(iter).await
+    }
+}
+
+#[cfg(all(test, any(feature = "alloc", feature = "std")))]
+mod test {
+    use super::Extend;
+    use crate::from_iter;
+    use crate::test::block_on;
+
+    async fn fill(mut sink: impl Extend<u8>) {
+        sink.extend(from_iter(0..3)).await;
+    }
+
+    #[test]
+    fn forwarding() {
+        block_on(async {
+            let mut vec = vec![9];
+            fill(&mut vec).await;
+            assert_eq!(vec, vec![9, 0, 1, 2]);
+
+            let mut boxed = Box::new(Vec::new());
+            boxed.extend(from_iter(0..2)).await;
+            fill(&mut boxed).await;
+            assert_eq!(*boxed, vec![0, 1, 0, 1, 2]);
+        })
+    }
+
+    #[test]
+    fn string() {
+        block_on(async {
+            let mut s = String::from("a");
+            Extend::extend(&mut s, from_iter(vec!['b', 'c'])).await;
+            Extend::extend(&mut s, from_iter(vec!["de", "f"])).await;
+            assert_eq!(s, "abcdef");
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_map() {
+        block_on(async {
+            let mut map = std::collections::HashMap::new();
+            map.insert("a", 1);
+            Extend::extend(&mut map, from_iter(vec![("a", 2), ("b", 3)])).await;
+            assert_eq!(map.len(), 2);
+            assert_eq!(map["a"], 2);
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn merge_extend() {
+        use super::MergeExtend;
+        use std::collections::{BTreeMap, HashMap};
+        block_on(async {
+            let mut counts = HashMap::new();
+            counts.insert("a", 1);
+            let words = from_iter(vec![("a", 1), ("b", 1), ("a", 1)]);
+            counts.extend_with(words, |count, n| *count += n).await;
+            assert_eq!(counts["a"], 3);
+            assert_eq!(counts["b"], 1);
+
+            let mut lists = BTreeMap::new();
+            let pairs = from_iter(vec![(1, vec!["x"]), (2, vec!["y"]), (1, vec!["z"])]);
+            lists
+                .extend_with(pairs, |list: &mut Vec<_>, mut more| list.append(&mut more))
+                .await;
+            assert_eq!(lists[&1], vec!["x", "z"]);
+            assert_eq!(lists[&2], vec!["y"]);
+        })
+    }
+
+    #[cfg(feature = "async-channel")]
+    #[test]
+    fn async_channel_sender() {
+        use core::cell::Cell;
+        use std::thread;
+        block_on(async {
+            let (mut tx, rx) = async_channel::bounded(1);
+            let consumer = thread::spawn(move || {
+                let mut out = Vec::new();
+                while let Ok(value) = rx.recv_blocking() {
+                    out.push(value);
+                }
+                out
+            });
+            tx.extend(from_iter(0..10)).await;
+            drop(tx);
+            assert_eq!(consumer.join().unwrap(), (0..10).collect::<Vec<_>>());
+
+            let pulled = Cell::new(0);
+            let (mut tx, rx) = async_channel::bounded(1);
+            drop(rx);
+            let source = (0..10).inspect(|_| pulled.set(pulled.get() + 1));
+            tx.extend(from_iter(source)).await;
+            assert_eq!(pulled.get(), 1);
+        })
+    }
+
+    #[cfg(feature = "futures-channel")]
+    #[test]
+    fn futures_channel_senders() {
+        use core::cell::Cell;
+        use futures_channel::mpsc::{self, TryRecvError};
+        use std::thread;
+        block_on(async {
+            let (mut tx, mut rx) = mpsc::channel(0);
+            let consumer = thread::spawn(move || {
+                let mut out = Vec::new();
+                loop {
+                    match rx.try_recv() {
+                        Ok(value) => out.push(value),
+                        Err(TryRecvError::Closed) => return out,
+                        Err(TryRecvError::Empty) => thread::yield_now(),
+                    }
+                }
+            });
+            tx.extend(from_iter(0..10)).await;
+            drop(tx);
+            assert_eq!(consumer.join().unwrap(), (0..10).collect::<Vec<_>>());
+
+            let (mut tx, mut rx) = mpsc::unbounded();
+            tx.extend(from_iter(0..3)).await;
+            drop(tx);
+            let mut out = Vec::new();
+            while let Ok(value) = rx.try_recv() {
+                out.push(value);
+            }
+            assert_eq!(out, vec![0, 1, 2]);
+
+            let pulled = Cell::new(0);
+            let (mut tx, rx) = mpsc::channel(0);
+            drop(rx);
+            let source = (0..10).inspect(|_| pulled.set(pulled.get() + 1));
+            tx.extend(from_iter(source)).await;
+            assert_eq!(pulled.get(), 1);
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_capacity_hints() {
+        use crate::Iterator;
+        use std::collections::{HashMap, HashSet};
+
+        /// Yields three items while claiming a huge lower bound.
+        struct Lying(u32);
+
+        impl Iterator for Lying {
+            type Item = u32;
+
+            async fn next(&mut self) -> Option<u32> {
+                self.0 += 1;
+                if self.0 <= 3 {
+                    Some(self.0)
+                } else {
+                    None
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (usize::MAX / 2, None)
+            }
+        }
+
+        block_on(async {
+            let map: HashMap<_, _> = from_iter(0..10_000)
+                .map(|x| async move { (x, x) })
+                .collect()
+                .await;
+            assert_eq!(map.len(), 10_000);
+            assert!(map.capacity() >= 10_000);
+
+            let set: HashSet<u32> = from_iter(0..10_000).collect().await;
+            assert!(set.capacity() >= 10_000);
+
+            let set: HashSet<u32> = Lying(0).collect().await;
+            assert_eq!(set.len(), 3);
+            assert!(set.capacity() <= 2 * super::MAX_HASH_RESERVE);
+
+            let mut map = HashMap::new();
+            Extend::extend(&mut map, Lying(0).map(|x| async move { (x, ()) })).await;
+            assert!(map.capacity() <= 2 * super::MAX_HASH_RESERVE);
+
+            let map = Lying(0)
+                .map(|x| async move { (x % 2, x) })
+                .collect_map_with(async |a: &mut u32, b| *a += b)
+                .await;
+            assert_eq!(map[&1], 4);
+            assert!(map.capacity() <= 2 * super::MAX_HASH_RESERVE);
+
+            let map = Lying(0)
+                .map(|x| async move { (x % 2, x) })
+                .collect_map_first()
+                .await;
+            assert_eq!(map[&1], 1);
+            assert!(map.capacity() <= 2 * super::MAX_HASH_RESERVE);
+
+            let groups = Lying(0).group_map(|x| (x % 2, x)).await;
+            assert_eq!(groups[&1], vec![1, 3]);
+            assert!(groups.capacity() <= 2 * super::MAX_HASH_RESERVE);
+        })
+    }
+}
@@ -1,23 +1,339 @@
+use crate::iter::ResultShunt;
 use crate::IntoIterator;
 
 #[cfg(any(feature = "alloc", feature = "std"))]
-use crate::Iterator;
+use crate::extend::Extend;
 
 /// Conversion from an [`Iterator`].
-
 pub trait FromIterator<A>: Sized {
     /// Creates a value from an iterator.
     async fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self;
 }
 
+/// Collects an iterator by extending an empty collection, so the reservation
+/// policy for each collection only lives in its `Extend` impl.
+#[cfg(any(feature = "alloc", feature = "std"))]
+async fn extend_default<A, C, I>(iter: I) -> C
+where
+    C: Default + Extend<A>,
+    I: IntoIterator<Item = A>,
+{
+    let mut output = C::default();
+    output.extend(iter).await;
+    output
+}
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 impl<T> FromIterator<T> for std::vec::Vec<T> {
     async fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> std::vec::Vec<T> {
-        let mut iter = iter.into_iter().await;
-        let mut output = std::vec::Vec::with_capacity(iter.size_hint().1.unwrap_or_default());
-        while let Some(item) = iter.next().await {
-            output.push(item);
+        extend_default(iter).await
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T> FromIterator<T> for std::collections::VecDeque<T> {
+    async fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        extend_default(iter).await
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl FromIterator<char> for std::string::String {
+    async fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> std::string::String {
+        extend_default(iter).await
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<'a> FromIterator<&'a str> for std::string::String {
+    async fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> std::string::String {
+        extend_default(iter).await
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> FromIterator<(K, V)> for std::collections::HashMap<K, V, S>
+where
+    K: Eq + core::hash::Hash,
+    S: core::hash::BuildHasher + Default,
+{
+    async fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        extend_default(iter).await
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S> FromIterator<T> for std::collections::HashSet<T, S>
+where
+    T: Eq + core::hash::Hash,
+    S: core::hash::BuildHasher + Default,
+{
+    async fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        extend_default(iter).await
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: AsRef<std::path::Path>> FromIterator<P> for std::path::PathBuf {
+    async fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        extend_default(iter).await
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: AsRef<std::ffi::OsStr>> FromIterator<S> for std::ffi::OsString {
+    async fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        extend_default(iter).await
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<K: Ord, V> FromIterator<(K, V)> for std::collections::BTreeMap<K, V> {
+    async fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        extend_default(iter).await
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T: Ord> FromIterator<T> for std::collections::BTreeSet<T> {
+    async fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        extend_default(iter).await
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, C: FromIterator<A>> FromIterator<A> for std::boxed::Box<C> {
+    async fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> std::boxed::Box<C> {
+        std::boxed::Box::new(C::from_iter(iter).await)
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T> FromIterator<T> for std::boxed::Box<[T]> {
+    async fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        std::vec::Vec::from_iter(iter).await.into_boxed_slice()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T> FromIterator<T> for std::rc::Rc<[T]> {
+    async fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        std::vec::Vec::from_iter(iter).await.into()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T> FromIterator<T> for std::sync::Arc<[T]> {
+    async fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        std::vec::Vec::from_iter(iter).await.into()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl FromIterator<char> for std::boxed::Box<str> {
+    async fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        std::string::String::from_iter(iter).await.into_boxed_str()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<'a> FromIterator<&'a str> for std::boxed::Box<str> {
+    async fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        std::string::String::from_iter(iter).await.into_boxed_str()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl FromIterator<char> for std::rc::Rc<str> {
+    async fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        std::string::String::from_iter(iter).await.into()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<'a> FromIterator<&'a str> for std::rc::Rc<str> {
+    async fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        std::string::String::from_iter(iter).await.into()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl FromIterator<char> for std::sync::Arc<str> {
+    async fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        std::string::String::from_iter(iter).await.into()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<'a> FromIterator<&'a str> for std::sync::Arc<str> {
+    async fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        std::string::String::from_iter(iter).await.into()
+    }
+}
+
+impl<A, E, V: FromIterator<A>> FromIterator<Result<A, E>> for Result<V, E> {
+    async fn from_iter<I: IntoIterator<Item = Result<A, E>>>(iter: I) -> Result<V, E> {
+        let mut error = None;
+        let shunt = ResultShunt::new(iter.into_iter().await, &mut error);
+        let output = V::from_iter(shunt).await;
+        match error {
+            Some(err) => Err(err),
+            None => Ok(output),
         }
-        output
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+    use std::collections::{BTreeSet, HashMap, HashSet};
+
+    #[test]
+    fn try_collect_into_map() {
+        block_on(async {
+            let iter = from_iter(vec![Ok::<_, &str>(("a", 1)), Ok(("b", 2))]);
+            let map: Result<HashMap<_, _>, _> = iter.collect().await;
+            let map = map.unwrap();
+            assert_eq!(map.len(), 2);
+            assert_eq!(map["b"], 2);
+
+            let iter = from_iter(vec![Ok(("a", 1)), Err("boom"), Ok(("b", 2))]);
+            let map: Result<HashMap<_, _>, _> = iter.try_collect().await;
+            assert_eq!(map, Err("boom"));
+        })
+    }
+
+    #[test]
+    fn try_collect_into_sets() {
+        block_on(async {
+            let iter = from_iter(vec![Ok::<_, &str>(2), Ok(1), Ok(2)]);
+            let set: Result<HashSet<_>, _> = iter.collect().await;
+            assert_eq!(set, Ok(HashSet::from([1, 2])));
+
+            let iter = from_iter(vec![Ok(1), Err("boom"), Ok(2)]);
+            let set: Result<HashSet<_>, _> = iter.try_collect().await;
+            assert_eq!(set, Err("boom"));
+
+            let iter = from_iter(vec![Ok::<_, &str>(3), Ok(1), Ok(3), Ok(2)]);
+            let set: Result<BTreeSet<_>, _> = iter.collect().await;
+            assert_eq!(set.unwrap().into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+            let iter = from_iter(vec![Ok(1), Err("boom"), Ok(2)]);
+            let set: Result<BTreeSet<_>, _> = iter.try_collect().await;
+            assert_eq!(set, Err("boom"));
+        })
+    }
+
+    #[test]
+    fn capacity_matches_extend() {
+        use crate::prelude::Extend;
+        block_on(async {
+            let collected: Vec<_> = from_iter(0..10).collect().await;
+            let mut extended = Vec::new();
+            Extend::extend(&mut extended, from_iter(0..10)).await;
+            assert_eq!(collected, extended);
+            assert_eq!(collected.capacity(), extended.capacity());
+
+            let filtered: Vec<_> = from_iter(0..10).filter_sync(|_| false).collect().await;
+            let mut extended = Vec::new();
+            Extend::extend(&mut extended, from_iter(0..10).filter_sync(|_| false)).await;
+            assert_eq!(filtered.capacity(), extended.capacity());
+        })
+    }
+
+    #[test]
+    fn collect_into_box() {
+        block_on(async {
+            let boxed: Box<Vec<_>> = from_iter(0..3).collect().await;
+            assert_eq!(*boxed, vec![0, 1, 2]);
+
+            let slice: std::rc::Rc<[_]> = from_iter(0..3).collect().await;
+            assert_eq!(&*slice, &[0, 1, 2]);
+            let slice: std::sync::Arc<[_]> = from_iter(0..3).collect().await;
+            assert_eq!(&*slice, &[0, 1, 2]);
+        })
+    }
+
+    #[test]
+    fn try_collect_into_string() {
+        block_on(async {
+            let iter = from_iter("héllo".chars().map(Ok::<_, &str>));
+            let s: Result<String, _> = iter.collect().await;
+            assert_eq!(s.as_deref(), Ok("héllo"));
+
+            let iter = from_iter(vec![Ok('a'), Err("invalid byte"), Ok('b')]);
+            let s: Result<String, _> = iter.collect().await;
+            assert_eq!(s, Err("invalid byte"));
+        })
+    }
+
+    #[test]
+    fn collect_into_path_and_os_string() {
+        use std::ffi::{OsStr, OsString};
+        use std::path::{Path, PathBuf};
+        block_on(async {
+            let path: PathBuf = from_iter(vec!["usr", "local", "bin"]).collect().await;
+            assert_eq!(path, Path::new("usr").join("local").join("bin"));
+
+            let pieces = vec![OsStr::new("foo"), OsStr::new("-"), OsStr::new("bar")];
+            let s: OsString = from_iter(pieces).collect().await;
+            assert_eq!(s, "foo-bar");
+        })
+    }
+
+    #[test]
+    fn try_collect_matrix() {
+        use std::collections::{BTreeMap, HashSet};
+        block_on(async {
+            let set: Result<HashSet<_>, _> = from_iter(vec![Ok::<_, &str>(1), Ok(2), Ok(1)])
+                .collect()
+                .await;
+            assert_eq!(set.map(|s| s.len()), Ok(2));
+            let set: Result<HashSet<i32>, _> =
+                from_iter(vec![Ok(1), Err("set"), Ok(2)]).collect().await;
+            assert_eq!(set, Err("set"));
+
+            let s: Result<String, _> = from_iter(vec![Ok::<_, &str>("ab"), Ok("cd")])
+                .collect()
+                .await;
+            assert_eq!(s.as_deref(), Ok("abcd"));
+            let s: Result<String, _> = from_iter(vec![Ok("ab"), Err("string")]).collect().await;
+            assert_eq!(s, Err("string"));
+
+            let map: Result<BTreeMap<_, _>, _> =
+                from_iter(vec![Ok::<_, &str>((2, 'b')), Ok((1, 'a'))])
+                    .collect()
+                    .await;
+            let map = map.map(|m| m.into_iter().collect::<Vec<_>>());
+            assert_eq!(map, Ok(vec![(1, 'a'), (2, 'b')]));
+            let map: Result<BTreeMap<i32, char>, _> =
+                from_iter(vec![Err("map"), Ok((1, 'a'))]).collect().await;
+            assert_eq!(map, Err("map"));
+        })
+    }
+
+    #[test]
+    fn collect_into_shared_str() {
+        use std::rc::Rc;
+        use std::sync::Arc;
+        block_on(async {
+            let s: Box<str> = from_iter(vec!["hé", "llo", " wörld"]).collect().await;
+            assert_eq!(&*s, "héllo wörld");
+            assert_eq!(s.len(), 13);
+            let s: Rc<str> = from_iter("€uro".chars()).collect().await;
+            assert_eq!(&*s, "€uro");
+            assert_eq!(s.len(), 6);
+            let s: Arc<str> = from_iter(vec!["日本", "語"]).collect().await;
+            assert_eq!(&*s, "日本語");
+            assert_eq!(s.len(), 9);
+
+            let s: Box<str> = from_iter("".chars()).collect().await;
+            assert_eq!(s.len(), 0);
+            let s: Rc<str> = from_iter(Vec::<&str>::new()).collect().await;
+            assert_eq!(s.len(), 0);
+            let s: Arc<str> = from_iter("".chars()).collect().await;
+            assert_eq!(s.len(), 0);
+        })
     }
 }
@@ -0,0 +1,9 @@
+use crate::Iterator;
+
+/// An iterator that always continues to yield `None` when exhausted.
+///
+/// Calling `next` on a fused iterator that has returned `None` once is
+/// guaranteed to return `None` again, without polling any underlying source.
+pub trait FusedIterator: Iterator {}
+
+impl<I: FusedIterator + ?Sized> FusedIterator for &mut I {}
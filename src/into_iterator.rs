@@ -1,4 +1,4 @@
-use crate::Iterator;
+use crate::{from_iter, FromIter, Iterator};
 
 /// Conversion into an [`Iterator`].
 pub trait IntoIterator {
@@ -20,3 +20,71 @@ impl<I: Iterator> IntoIterator for I {
         self
     }
 }
+
+impl<T> IntoIterator for Option<T> {
+    type Item = T;
+    type IntoIter = FromIter<core::option::IntoIter<T>>;
+
+    async fn into_iter(self) -> Self::IntoIter {
+        from_iter(self)
+    }
+}
+
+impl<T, E> IntoIterator for Result<T, E> {
+    type Item = T;
+    type IntoIter = FromIter<core::result::IntoIter<T>>;
+
+    async fn into_iter(self) -> Self::IntoIter {
+        from_iter(self)
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T> IntoIterator for std::boxed::Box<[T]> {
+    type Item = T;
+    type IntoIter = FromIter<std::vec::IntoIter<T>>;
+
+    async fn into_iter(self) -> Self::IntoIter {
+        from_iter(self.into_vec())
+    }
+}
+
+#[cfg(all(test, any(feature = "alloc", feature = "std")))]
+mod test {
+    use super::IntoIterator;
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn option() {
+        block_on(async {
+            let out: Vec<_> = IntoIterator::into_iter(Some(1)).await.collect().await;
+            assert_eq!(out, vec![1]);
+            let out: Vec<i32> = IntoIterator::into_iter(None).await.collect().await;
+            assert!(out.is_empty());
+        })
+    }
+
+    #[test]
+    fn result() {
+        block_on(async {
+            let ok = IntoIterator::into_iter(Ok::<_, &str>(2)).await;
+            let err = IntoIterator::into_iter(Err::<i32, _>("discarded")).await;
+            let out: Vec<_> = from_iter(0..2).chain(ok).chain(err).collect().await;
+            assert_eq!(out, vec![0, 1, 2]);
+        })
+    }
+
+    #[test]
+    fn boxed_slice_round_trip() {
+        use crate::ExactSizeIterator;
+
+        block_on(async {
+            let boxed: Box<[u8]> = from_iter(vec![3, 1, 2]).collect().await;
+            let iter = IntoIterator::into_iter(boxed).await;
+            assert_eq!(iter.len(), 3);
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec![3, 1, 2]);
+        })
+    }
+}
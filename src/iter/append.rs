@@ -0,0 +1,79 @@
+use crate::{ExactSizeIterator, FusedIterator, Iterator};
+
+/// An iterator that yields an extra item after the items of another
+/// iterator.
+#[derive(Debug)]
+pub struct Append<I: Iterator> {
+    stream: I,
+    item: Option<I::Item>,
+    /// Whether the underlying iterator has returned `None`.
+    done: bool,
+}
+
+impl<I: Iterator> Append<I> {
+    pub(crate) fn new(stream: I, item: I::Item) -> Self {
+        Self {
+            stream,
+            item: Some(item),
+            done: false,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Append<I> {
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if !self.done {
+            if let Some(item) = self.stream.next().await {
+                return Some(item);
+            }
+            self.done = true;
+        }
+        self.item.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let extra = usize::from(self.item.is_some());
+        if self.done {
+            return (extra, Some(extra));
+        }
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(extra),
+            upper.and_then(|upper| upper.checked_add(extra)),
+        )
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Append<I> {}
+
+impl<I: Iterator> FusedIterator for Append<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn header_and_trailer() {
+        block_on(async {
+            let iter = from_iter(vec!["a", "b"])
+                .prepend("header")
+                .append("trailer");
+            assert_eq!(iter.size_hint(), (4, Some(4)));
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec!["header", "a", "b", "trailer"]);
+        })
+    }
+
+    #[test]
+    fn empty_source() {
+        block_on(async {
+            let mut iter = from_iter(Vec::<i32>::new()).append(1);
+            assert_eq!(iter.next().await, Some(1));
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+        })
+    }
+}
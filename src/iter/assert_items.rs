@@ -0,0 +1,92 @@
+use crate::{FusedIterator, Iterator};
+use std::string::String;
+
+/// An iterator that checks an invariant on every element of another
+/// iterator.
+#[derive(Debug)]
+pub struct AssertItems<I, F> {
+    stream: I,
+    index: usize,
+    check: F,
+}
+
+impl<I, F> AssertItems<I, F> {
+    pub(crate) fn new(stream: I, check: F) -> Self {
+        Self {
+            stream,
+            index: 0,
+            check,
+        }
+    }
+}
+
+impl<I, F> Iterator for AssertItems<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> Result<(), String>,
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await?;
+        if cfg!(any(debug_assertions, feature = "log-assertions")) {
+            let index = self.index;
+            self.index += 1;
+            if let Err(msg) = (self.check)(&item) {
+                fail(index, &msg);
+            }
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<I, F> FusedIterator for AssertItems<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item) -> Result<(), String>,
+{
+}
+
+fn fail(index: usize, msg: &str) {
+    if cfg!(debug_assertions) {
+        panic!("assertion failed for item {}: {}", index, msg);
+    }
+    #[cfg(feature = "log-assertions")]
+    std::eprintln!("assertion failed for item {}: {}", index, msg);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    fn non_negative(x: &i32) -> Result<(), String> {
+        if *x < 0 {
+            Err(format!("{} is negative", x))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn passes_items_through() {
+        block_on(async {
+            let out: Vec<_> = from_iter(0..3).assert_items(non_negative).collect().await;
+            assert_eq!(out, vec![0, 1, 2]);
+        })
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "assertion failed for item 2: -1 is negative")]
+    fn panics_with_index() {
+        block_on(async {
+            let iter = from_iter(vec![3, 2, -1, 0]).assert_items(non_negative);
+            let _: Vec<_> = iter.collect().await;
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use crate::Iterator;
+
+/// An iterator which uses a closure to consume any number of items from
+/// another iterator for each value it yields.
+#[derive(Debug)]
+pub struct Batching<I, F> {
+    stream: I,
+    f: F,
+}
+
+impl<I, F> Batching<I, F> {
+    pub(crate) fn new(stream: I, f: F) -> Self {
+        Self { stream, f }
+    }
+}
+
+impl<I, F, B> Iterator for Batching<I, F>
+where
+    I: Iterator,
+    F: AsyncFnMut(&mut I) -> Option<B>,
+{
+    type Item = B;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        (self.f)(&mut self.stream).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn pairs() {
+        block_on(async {
+            let out: Vec<_> = from_iter(0..5)
+                .batching(async |iter| {
+                    let first = iter.next().await?;
+                    Some((first, iter.next().await))
+                })
+                .collect()
+                .await;
+            assert_eq!(out, vec![(0, Some(1)), (2, Some(3)), (4, None)]);
+        })
+    }
+
+    #[test]
+    fn delimited() {
+        block_on(async {
+            let out: Vec<Vec<_>> = from_iter("ab,c,,de".chars())
+                .batching(async |iter| {
+                    let mut frame = Vec::new();
+                    loop {
+                        match iter.next().await {
+                            Some(',') => return Some(frame),
+                            Some(c) => frame.push(c),
+                            None if frame.is_empty() => return None,
+                            None => return Some(frame),
+                        }
+                    }
+                })
+                .collect()
+                .await;
+            assert_eq!(out, vec![vec!['a', 'b'], vec!['c'], vec![], vec!['d', 'e']]);
+        })
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{FusedIterator, Iterator};
+use std::vec::Vec;
+
+/// An iterator that batches the items of another iterator for as long as a
+/// predicate accepts them.
+///
+/// Like [`Chunks`](super::Chunks), items are buffered in the iterator itself,
+/// so dropping an in-flight call to `next` doesn't lose them.
+#[derive(Debug)]
+pub struct BufferWhile<I: Iterator, P> {
+    stream: I,
+    predicate: P,
+    buf: Vec<I::Item>,
+    /// The item currently being checked against `buf`.
+    candidate: Option<I::Item>,
+    done: bool,
+}
+
+impl<I: Iterator, P> BufferWhile<I, P> {
+    pub(crate) fn new(stream: I, predicate: P) -> Self {
+        Self {
+            stream,
+            predicate,
+            buf: Vec::new(),
+            candidate: None,
+            done: false,
+        }
+    }
+}
+
+impl<I, P> Iterator for BufferWhile<I, P>
+where
+    I: Iterator,
+    P: AsyncFnMut(&[I::Item], &I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.candidate.is_none() {
+                if self.done {
+                    break;
+                }
+                match self.stream.next().await {
+                    Some(item) => self.candidate = Some(item),
+                    None => {
+                        self.done = true;
+                        break;
+                    }
+                }
+            }
+            if let Some(item) = &self.candidate {
+                if !self.buf.is_empty() && !(self.predicate)(&self.buf, item).await {
+                    let batch = core::mem::take(&mut self.buf);
+                    self.buf.extend(self.candidate.take());
+                    return Some(batch);
+                }
+            }
+            self.buf.extend(self.candidate.take());
+        }
+        if self.buf.is_empty() {
+            None
+        } else {
+            Some(core::mem::take(&mut self.buf))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The buffered items and the candidate may each end up in a batch of
+        // their own.
+        let buffered = usize::from(!self.buf.is_empty()) + usize::from(self.candidate.is_some());
+        if self.done {
+            return (usize::from(buffered > 0), Some(buffered));
+        }
+        let (lower, upper) = self.stream.size_hint();
+        let lower = usize::from(lower > 0 || buffered > 0);
+        (lower, upper.and_then(|n| n.checked_add(buffered)))
+    }
+}
+
+impl<I, P> FusedIterator for BufferWhile<I, P>
+where
+    I: Iterator,
+    P: AsyncFnMut(&[I::Item], &I::Item) -> bool,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::{block_on, cancel_after, Yielding};
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn fixed_size() {
+        block_on(async {
+            let out: Vec<_> = from_iter(0..7)
+                .buffer_while(async |batch: &[i32], _: &i32| batch.len() < 3)
+                .collect()
+                .await;
+            assert_eq!(out, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+
+            let out: Vec<Vec<i32>> = from_iter(vec![])
+                .buffer_while(async |_: &[i32], _: &i32| true)
+                .collect()
+                .await;
+            assert!(out.is_empty());
+        })
+    }
+
+    #[test]
+    fn size_budget() {
+        block_on(async {
+            let messages = vec!["ab", "cd", "efghijk", "l", "mn", "o"];
+            let out: Vec<_> = from_iter(messages)
+                .buffer_while(async |batch: &[&str], msg: &&str| {
+                    batch.iter().map(|m| m.len()).sum::<usize>() + msg.len() <= 4
+                })
+                .collect()
+                .await;
+            assert_eq!(
+                out,
+                vec![vec!["ab", "cd"], vec!["efghijk"], vec!["l", "mn", "o"]]
+            );
+        })
+    }
+
+    #[test]
+    fn infinite_size_hint() {
+        block_on(async {
+            let mut iter = from_iter(core::iter::repeat(1))
+                .buffer_while(async |batch: &[i32], _: &i32| batch.len() < 2);
+            assert_eq!(iter.next().await, Some(vec![1, 1]));
+            assert_eq!(iter.size_hint(), (1, None));
+        })
+    }
+
+    #[test]
+    fn cancelled_next_keeps_items() {
+        let mut iter =
+            Yielding::new(3).buffer_while(async |batch: &[usize], _: &usize| batch.len() < 2);
+        cancel_after(iter.next(), 2);
+        block_on(async {
+            assert_eq!(iter.next().await, Some(vec![0, 1]));
+            assert_eq!(iter.next().await, Some(vec![2]));
+            assert_eq!(iter.next().await, None);
+        })
+    }
+}
@@ -0,0 +1,101 @@
+use crate::Iterator;
+use core::mem;
+
+/// An iterator that links two iterators together, in a chain.
+#[derive(Debug)]
+pub struct Chain<A, B> {
+    state: State<A, B>,
+}
+
+#[derive(Debug)]
+enum State<A, B> {
+    /// Yielding items from the first iterator.
+    First(A, B),
+    /// The first iterator has been exhausted and dropped.
+    Second(B),
+    /// Transient state used while moving between the other two.
+    Empty,
+}
+
+impl<A, B> Chain<A, B> {
+    pub(crate) fn new(first: A, second: B) -> Self {
+        Self {
+            state: State::First(first, second),
+        }
+    }
+}
+
+impl<A, B> Iterator for Chain<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if let State::First(first, _) = &mut self.state {
+            if let Some(item) = first.next().await {
+                return Some(item);
+            }
+            if let State::First(_, second) = mem::replace(&mut self.state, State::Empty) {
+                self.state = State::Second(second);
+            }
+        }
+        match &mut self.state {
+            State::Second(second) => second.next().await,
+            _ => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.state {
+            State::First(first, second) => {
+                let (a_lower, a_upper) = first.size_hint();
+                let (b_lower, b_upper) = second.size_hint();
+                let lower = a_lower.saturating_add(b_lower);
+                let upper = match (a_upper, b_upper) {
+                    (Some(a), Some(b)) => a.checked_add(b),
+                    _ => None,
+                };
+                (lower, upper)
+            }
+            State::Second(second) => second.size_hint(),
+            State::Empty => (0, Some(0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    /// Yields a single item and panics if polled again after returning `None`.
+    struct Strict(u8);
+
+    impl Iterator for Strict {
+        type Item = u8;
+
+        async fn next(&mut self) -> Option<u8> {
+            self.0 += 1;
+            match self.0 {
+                1 => Some(1),
+                2 => None,
+                _ => panic!("polled after completion"),
+            }
+        }
+    }
+
+    #[test]
+    fn first_not_polled_after_exhaustion() {
+        block_on(async {
+            let mut iter = Strict(0).chain(from_iter(vec![2, 3]));
+            assert_eq!(iter.size_hint(), (2, None));
+            let mut out = Vec::new();
+            for _ in 0..5 {
+                out.push(iter.next().await);
+            }
+            assert_eq!(out, vec![Some(1), Some(2), Some(3), None, None]);
+        })
+    }
+}
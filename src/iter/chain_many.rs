@@ -0,0 +1,78 @@
+use crate::{FusedIterator, Iterator};
+use std::collections::VecDeque;
+
+/// Chains many iterators of the same type together, yielding the items of
+/// each in turn.
+///
+/// Unlike repeated calls to [`Iterator::chain`], this produces a single flat
+/// adapter regardless of how many iterators are chained.
+pub fn chain_many<I: Iterator>(sources: impl core::iter::IntoIterator<Item = I>) -> ChainMany<I> {
+    ChainMany {
+        sources: sources.into_iter().collect(),
+    }
+}
+
+/// An iterator that yields the items of many iterators in order.
+///
+/// This `struct` is created by the [`chain_many`] function.
+#[derive(Debug)]
+pub struct ChainMany<I> {
+    /// The sources which haven't been exhausted yet, current one first.
+    sources: VecDeque<I>,
+}
+
+impl<I: Iterator> Iterator for ChainMany<I> {
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let source = self.sources.front_mut()?;
+            if let Some(item) = source.next().await {
+                return Some(item);
+            }
+            self.sources.pop_front();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sources
+            .iter()
+            .map(Iterator::size_hint)
+            .fold((0, Some(0)), |(lower, upper), (l, u)| {
+                let upper = match (upper, u) {
+                    (Some(a), Some(b)) => a.checked_add(b),
+                    _ => None,
+                };
+                (lower.saturating_add(l), upper)
+            })
+    }
+}
+
+impl<I: Iterator> FusedIterator for ChainMany<I> {}
+
+#[cfg(test)]
+mod test {
+    use super::chain_many;
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn matches_nested_chains() {
+        block_on(async {
+            let source = |n: u32| from_iter(vec![n; n as usize]);
+            let iter = chain_many((0..5).map(source));
+            assert_eq!(iter.size_hint(), (10, Some(10)));
+            let flat: Vec<_> = iter.collect().await;
+
+            let nested: Vec<_> = source(0)
+                .chain(source(1))
+                .chain(source(2))
+                .chain(source(3))
+                .chain(source(4))
+                .collect()
+                .await;
+            assert_eq!(flat, nested);
+            assert_eq!(flat, vec![1, 2, 2, 3, 3, 3, 4, 4, 4, 4]);
+        })
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{FusedIterator, Iterator};
+use std::vec::Vec;
+
+/// An iterator that yields the items of another iterator in batches.
+///
+/// Items are buffered in the iterator itself rather than in the `next`
+/// future, so dropping an in-flight call to `next` doesn't lose them; they
+/// are yielded by the following call, or can be recovered with
+/// [`Chunks::take_partial`].
+#[derive(Debug)]
+pub struct Chunks<I: Iterator> {
+    stream: I,
+    size: usize,
+    buf: Vec<I::Item>,
+    done: bool,
+}
+
+impl<I: Iterator> Chunks<I> {
+    pub(crate) fn new(stream: I, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be greater than zero");
+        Self {
+            stream,
+            size,
+            buf: Vec::new(),
+            done: false,
+        }
+    }
+
+    /// Takes the items buffered towards the next chunk.
+    ///
+    /// This is empty unless a call to `next` was dropped before completing.
+    pub fn take_partial(&mut self) -> Vec<I::Item> {
+        core::mem::take(&mut self.buf)
+    }
+}
+
+impl<I: Iterator> Iterator for Chunks<I> {
+    type Item = Vec<I::Item>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            self.buf.reserve_exact(self.size);
+        }
+        while !self.done && self.buf.len() < self.size {
+            match self.stream.next().await {
+                Some(item) => self.buf.push(item),
+                None => self.done = true,
+            }
+        }
+        if self.buf.is_empty() {
+            None
+        } else {
+            Some(core::mem::take(&mut self.buf))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buf.len();
+        if self.done {
+            let n = usize::from(buffered > 0);
+            return (n, Some(n));
+        }
+        let (lower, upper) = self.stream.size_hint();
+        let chunks = |n: usize| n.saturating_add(buffered).div_ceil(self.size);
+        (chunks(lower), upper.map(chunks))
+    }
+}
+
+impl<I: Iterator> FusedIterator for Chunks<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::{block_on, cancel_after, Yielding};
+    use crate::{FusedIterator, Iterator};
+
+    /// Yields `0..len` and panics if polled again after returning `None`.
+    struct Strict {
+        next: usize,
+        len: usize,
+    }
+
+    impl Iterator for Strict {
+        type Item = usize;
+
+        async fn next(&mut self) -> Option<usize> {
+            assert!(self.next <= self.len, "polled after completion");
+            self.next += 1;
+            (self.next <= self.len).then_some(self.next - 1)
+        }
+    }
+
+    fn assert_fused<I: FusedIterator>(_: &I) {}
+
+    #[test]
+    fn partial_final_chunk() {
+        block_on(async {
+            let mut iter = Strict { next: 0, len: 5 }.chunks(2);
+            assert_fused(&iter);
+            assert_eq!(iter.next().await, Some(vec![0, 1]));
+            assert_eq!(iter.next().await, Some(vec![2, 3]));
+            assert_eq!(iter.next().await, Some(vec![4]));
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.next().await, None);
+        })
+    }
+
+    #[test]
+    fn cancelled_next_keeps_items() {
+        let mut iter = Yielding::new(3).chunks(2);
+        cancel_after(iter.next(), 2);
+        assert_eq!(iter.take_partial(), vec![0]);
+
+        cancel_after(iter.next(), 2);
+        block_on(async {
+            assert_eq!(iter.next().await, Some(vec![1, 2]));
+            assert_eq!(iter.next().await, None);
+        })
+    }
+}
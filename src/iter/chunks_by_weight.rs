@@ -0,0 +1,151 @@
+use crate::{FusedIterator, Iterator};
+use std::vec::Vec;
+
+/// An iterator that yields the items of another iterator in batches whose
+/// total weight stays within a budget.
+///
+/// Like [`Chunks`](super::Chunks), items are buffered in the iterator itself,
+/// so dropping an in-flight call to `next` doesn't lose them.
+#[derive(Debug)]
+pub struct ChunksByWeight<I: Iterator, F> {
+    stream: I,
+    max_weight: usize,
+    weigh: F,
+    buf: Vec<I::Item>,
+    /// The total weight of `buf`.
+    total: usize,
+    /// An item that didn't fit in the previous batch, with its weight.
+    pending: Option<(I::Item, usize)>,
+    done: bool,
+}
+
+impl<I: Iterator, F> ChunksByWeight<I, F> {
+    pub(crate) fn new(stream: I, max_weight: usize, weigh: F) -> Self {
+        Self {
+            stream,
+            max_weight,
+            weigh,
+            buf: Vec::new(),
+            total: 0,
+            pending: None,
+            done: false,
+        }
+    }
+}
+
+impl<I, F> Iterator for ChunksByWeight<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> usize,
+{
+    type Item = Vec<I::Item>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if let Some((item, weight)) = self.pending.take() {
+            self.buf.push(item);
+            self.total = weight;
+        }
+        while !self.done && (self.buf.is_empty() || self.total < self.max_weight) {
+            let item = match self.stream.next().await {
+                Some(item) => item,
+                None => {
+                    self.done = true;
+                    break;
+                }
+            };
+            let weight = (self.weigh)(&item);
+            if !self.buf.is_empty() && self.total.saturating_add(weight) > self.max_weight {
+                self.pending = Some((item, weight));
+                break;
+            }
+            self.buf.push(item);
+            self.total = self.total.saturating_add(weight);
+        }
+        self.total = 0;
+        if self.buf.is_empty() {
+            None
+        } else {
+            Some(core::mem::take(&mut self.buf))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending.is_some() || !self.buf.is_empty());
+        if self.done {
+            return (pending, Some(pending));
+        }
+        let (lower, upper) = self.stream.size_hint();
+        let lower = usize::from(lower > 0 || pending > 0);
+        (lower, upper.and_then(|n| n.checked_add(pending)))
+    }
+}
+
+impl<I, F> FusedIterator for ChunksByWeight<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> usize,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::{block_on, cancel_after, Yielding};
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn batches_by_length() {
+        block_on(async {
+            let messages = vec!["ab", "cd", "efghijk", "l", "mn", "o"];
+            let out: Vec<_> = from_iter(messages)
+                .chunks_by_weight(4, |msg| msg.len())
+                .collect()
+                .await;
+            assert_eq!(
+                out,
+                vec![vec!["ab", "cd"], vec!["efghijk"], vec!["l", "mn", "o"]]
+            );
+        })
+    }
+
+    #[test]
+    fn trailing_partial_batch() {
+        block_on(async {
+            let out: Vec<_> = from_iter(vec![3, 1, 2])
+                .chunks_by_weight(4, |n| *n)
+                .collect()
+                .await;
+            assert_eq!(out, vec![vec![3, 1], vec![2]]);
+        })
+    }
+
+    #[test]
+    fn zero_budget() {
+        block_on(async {
+            let out: Vec<_> = from_iter(vec![1, 2, 3])
+                .chunks_by_weight(0, |_| 1)
+                .collect()
+                .await;
+            assert_eq!(out, vec![vec![1], vec![2], vec![3]]);
+        })
+    }
+
+    #[test]
+    fn infinite_size_hint() {
+        block_on(async {
+            let mut iter = from_iter(core::iter::repeat(3)).chunks_by_weight(4, |n| *n);
+            assert_eq!(iter.next().await, Some(vec![3]));
+            assert_eq!(iter.size_hint(), (1, None));
+        })
+    }
+
+    #[test]
+    fn cancelled_next_keeps_items() {
+        let mut iter = Yielding::new(3).chunks_by_weight(2, |_| 1);
+        cancel_after(iter.next(), 2);
+        block_on(async {
+            assert_eq!(iter.next().await, Some(vec![0, 1]));
+            assert_eq!(iter.next().await, Some(vec![2]));
+            assert_eq!(iter.next().await, None);
+        })
+    }
+}
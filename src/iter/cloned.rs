@@ -0,0 +1,44 @@
+use crate::{ExactSizeIterator, FusedIterator, Iterator};
+
+/// An iterator that clones the elements of an underlying iterator.
+#[derive(Debug, Clone)]
+pub struct Cloned<I> {
+    stream: I,
+}
+
+impl<I> Cloned<I> {
+    pub(crate) fn new(stream: I) -> Self {
+        Self { stream }
+    }
+}
+
+impl<'a, I, T> Iterator for Cloned<I>
+where
+    I: Iterator<Item = &'a T>,
+    T: Clone + 'a,
+{
+    type Item = T;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await;
+        item.cloned()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<'a, I, T> ExactSizeIterator for Cloned<I>
+where
+    I: ExactSizeIterator<Item = &'a T>,
+    T: Clone + 'a,
+{
+}
+
+impl<'a, I, T> FusedIterator for Cloned<I>
+where
+    I: FusedIterator<Item = &'a T>,
+    T: Clone + 'a,
+{
+}
@@ -0,0 +1,105 @@
+use crate::{FusedIterator, Iterator};
+
+/// An iterator that merges adjacent items of another iterator with a
+/// function.
+#[derive(Debug)]
+pub struct Coalesce<I: Iterator, F> {
+    stream: I,
+    /// The item being merged into, kept here so a cancelled call doesn't
+    /// lose it while the next item is read.
+    pending: Option<I::Item>,
+    f: F,
+}
+
+impl<I: Iterator, F> Coalesce<I, F> {
+    pub(crate) fn new(stream: I, f: F) -> Self {
+        Self {
+            stream,
+            pending: None,
+            f,
+        }
+    }
+}
+
+impl<I, F> Iterator for Coalesce<I, F>
+where
+    I: Iterator,
+    F: AsyncFnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>,
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.pending.is_none() {
+            self.pending = Some(self.stream.next().await?);
+        }
+        while let Some(item) = self.stream.next().await {
+            let acc = self.pending.take()?;
+            match (self.f)(acc, item).await {
+                Ok(merged) => self.pending = Some(merged),
+                Err((done, next)) => {
+                    self.pending = Some(next);
+                    return Some(done);
+                }
+            }
+        }
+        self.pending.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending.is_some());
+        let (lower, upper) = self.stream.size_hint();
+        let lower = usize::from(lower > 0 || pending > 0);
+        (lower, upper.and_then(|n| n.checked_add(pending)))
+    }
+}
+
+impl<I, F> FusedIterator for Coalesce<I, F>
+where
+    I: FusedIterator,
+    F: AsyncFnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::{block_on, cancel_after, Yielding};
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn sums_equal_parity_runs() {
+        block_on(async {
+            let numbers = vec![1, 3, 2, 4, 6, 5, 8];
+            let out: Vec<_> = from_iter(numbers.into_iter().map(|n| (n % 2, n)))
+                .coalesce(|a: (i32, i32), b: (i32, i32)| async move {
+                    if a.0 == b.0 {
+                        Ok((a.0, a.1 + b.1))
+                    } else {
+                        Err((a, b))
+                    }
+                })
+                .collect()
+                .await;
+            assert_eq!(out, vec![(1, 4), (0, 12), (1, 5), (0, 8)]);
+        })
+    }
+
+    #[test]
+    fn infinite_size_hint() {
+        block_on(async {
+            let mut iter = from_iter(core::iter::repeat(1))
+                .coalesce(|a: i32, b: i32| async move { Err((a, b)) });
+            assert_eq!(iter.next().await, Some(1));
+            assert_eq!(iter.size_hint(), (1, None));
+        })
+    }
+
+    #[test]
+    fn cancelled_next_keeps_items() {
+        let mut iter = Yielding::new(3).coalesce(|a: usize, b: usize| async move { Ok(a + b) });
+        cancel_after(iter.next(), 2);
+        block_on(async {
+            assert_eq!(iter.next().await, Some(3));
+            assert_eq!(iter.next().await, None);
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{ExactSizeIterator, FusedIterator, Iterator};
+
+/// An iterator that copies the elements of an underlying iterator.
+#[derive(Debug, Clone)]
+pub struct Copied<I> {
+    stream: I,
+}
+
+impl<I> Copied<I> {
+    pub(crate) fn new(stream: I) -> Self {
+        Self { stream }
+    }
+}
+
+impl<'a, I, T> Iterator for Copied<I>
+where
+    I: Iterator<Item = &'a T>,
+    T: Copy + 'a,
+{
+    type Item = T;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await;
+        item.copied()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<'a, I, T> ExactSizeIterator for Copied<I>
+where
+    I: ExactSizeIterator<Item = &'a T>,
+    T: Copy + 'a,
+{
+}
+
+impl<'a, I, T> FusedIterator for Copied<I>
+where
+    I: FusedIterator<Item = &'a T>,
+    T: Copy + 'a,
+{
+}
@@ -0,0 +1,111 @@
+use crate::{FusedIterator, Iterator};
+
+/// An iterator that replays another iterator a fixed number of times.
+#[derive(Debug)]
+pub struct CycleN<I> {
+    /// The pristine iterator, cloned for each new round. Taken for the final
+    /// round.
+    orig: Option<I>,
+    current: Option<I>,
+    /// The number of rounds not yet started.
+    remaining: usize,
+    /// Whether the current round has yielded an item.
+    yielded: bool,
+}
+
+impl<I> CycleN<I> {
+    pub(crate) fn new(stream: I, n: usize) -> Self {
+        Self {
+            orig: if n == 0 { None } else { Some(stream) },
+            current: None,
+            remaining: n,
+            yielded: false,
+        }
+    }
+}
+
+impl<I: Iterator + Clone> Iterator for CycleN<I> {
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(current) = &mut self.current {
+                if let Some(item) = current.next().await {
+                    self.yielded = true;
+                    return Some(item);
+                }
+                self.current = None;
+                if !self.yielded {
+                    // An empty round means every other round is empty too.
+                    self.orig = None;
+                    self.remaining = 0;
+                }
+            }
+            if self.remaining == 0 {
+                return None;
+            }
+            self.remaining -= 1;
+            self.yielded = false;
+            self.current = if self.remaining == 0 {
+                self.orig.take()
+            } else {
+                self.orig.clone()
+            };
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (cur_lower, cur_upper) = match &self.current {
+            Some(current) => current.size_hint(),
+            None => (0, Some(0)),
+        };
+        let (lower, upper) = match &self.orig {
+            Some(orig) => orig.size_hint(),
+            None => (0, Some(0)),
+        };
+        let lower = lower
+            .saturating_mul(self.remaining)
+            .saturating_add(cur_lower);
+        let upper = match (upper, cur_upper) {
+            (Some(upper), Some(cur_upper)) => upper
+                .checked_mul(self.remaining)
+                .and_then(|n| n.checked_add(cur_upper)),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<I: Iterator + Clone> FusedIterator for CycleN<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn replays_n_times() {
+        block_on(async {
+            let iter = from_iter(vec![1, 2]).cycle_n(3);
+            assert_eq!(iter.size_hint(), (6, Some(6)));
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec![1, 2, 1, 2, 1, 2]);
+
+            let out: Vec<_> = from_iter(vec![1, 2]).cycle_n(1).collect().await;
+            assert_eq!(out, vec![1, 2]);
+
+            let iter = from_iter(vec![1, 2]).cycle_n(0);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+            let out: Vec<i32> = iter.collect().await;
+            assert!(out.is_empty());
+        })
+    }
+
+    #[test]
+    fn empty_source() {
+        block_on(async {
+            let out: Vec<i32> = from_iter(vec![]).cycle_n(usize::MAX).collect().await;
+            assert!(out.is_empty());
+        })
+    }
+}
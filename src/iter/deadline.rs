@@ -0,0 +1,84 @@
+use core::future::{poll_fn, Future};
+use core::pin::pin;
+use core::task::Poll;
+
+/// Drives `fut` until it completes or `deadline` fires, whichever happens
+/// first.
+///
+/// `fut` is polled before `deadline`, so a value that is ready by the time the
+/// deadline passes is still returned. Returns `None` on timeout, dropping
+/// `fut`.
+pub(crate) async fn race_deadline<F, D>(fut: F, deadline: D) -> Option<F::Output>
+where
+    F: Future,
+    D: Future<Output = ()>,
+{
+    let mut fut = pin!(fut);
+    let mut deadline = pin!(deadline);
+    poll_fn(|cx| {
+        if let Poll::Ready(out) = fut.as_mut().poll(cx) {
+            return Poll::Ready(Some(out));
+        }
+        deadline.as_mut().poll(cx).map(|()| None)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod test {
+    use super::race_deadline;
+    use crate::test::{block_on, Ticks, Yielding};
+    use crate::{from_iter, Iterator};
+    use core::time::Duration;
+
+    /// Never yields an item.
+    struct Stalled;
+
+    impl Iterator for Stalled {
+        type Item = usize;
+
+        async fn next(&mut self) -> Option<usize> {
+            core::future::pending().await
+        }
+    }
+
+    #[test]
+    fn races_deadline() {
+        block_on(async {
+            let ticks = || Ticks::new(Duration::from_millis(1));
+            let mut iter = Yielding::new(2).chain(Stalled);
+            assert_eq!(race_deadline(iter.next(), ticks()).await, Some(Some(0)));
+            assert_eq!(race_deadline(iter.next(), ticks()).await, Some(Some(1)));
+            assert_eq!(race_deadline(iter.next(), ticks()).await, None);
+
+            let mut iter = from_iter(0..1);
+            let expired = Ticks::new(Duration::ZERO);
+            assert_eq!(race_deadline(iter.next(), expired).await, Some(Some(0)));
+        })
+    }
+
+    #[test]
+    fn next_timeout() {
+        block_on(async {
+            // A ready item wins even against an already elapsed timeout, and
+            // a source that never yields always times out, so neither case
+            // depends on how long the timer actually takes.
+            let mut iter = from_iter(0..1);
+            assert_eq!(iter.next_timeout(Duration::ZERO).await, Some(Some(0)));
+            assert_eq!(iter.next_timeout(Duration::ZERO).await, Some(None));
+
+            let mut iter = Stalled;
+            assert_eq!(iter.next_timeout(Duration::from_millis(1)).await, None);
+        })
+    }
+
+    #[test]
+    fn next_timeout_keeps_partial_chunk() {
+        block_on(async {
+            let mut iter = Yielding::new(3).chain(Stalled).chunks(4);
+            let ticks = Ticks::new(Duration::from_millis(10));
+            assert_eq!(race_deadline(iter.next(), ticks).await, None);
+            assert_eq!(iter.take_partial(), vec![0, 1, 2]);
+        })
+    }
+}
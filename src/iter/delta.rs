@@ -0,0 +1,78 @@
+use crate::{FusedIterator, Iterator};
+use core::ops::Sub;
+
+/// An iterator that yields the differences between successive elements of
+/// another iterator.
+#[derive(Debug)]
+pub struct Delta<I: Iterator> {
+    stream: I,
+    prev: Option<I::Item>,
+}
+
+impl<I: Iterator> Delta<I> {
+    pub(crate) fn new(stream: I) -> Self {
+        Self { stream, prev: None }
+    }
+}
+
+impl<I> Iterator for Delta<I>
+where
+    I: Iterator,
+    I::Item: Sub + Copy,
+{
+    type Item = <I::Item as Sub>::Output;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let prev = match self.prev {
+            Some(prev) => prev,
+            None => *self.prev.insert(self.stream.next().await?),
+        };
+        let next = self.stream.next().await?;
+        self.prev = Some(next);
+        Some(next - prev)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        match self.prev {
+            Some(_) => (lower, upper),
+            None => (lower.saturating_sub(1), upper.map(|n| n.saturating_sub(1))),
+        }
+    }
+}
+
+impl<I> FusedIterator for Delta<I>
+where
+    I: FusedIterator,
+    I::Item: Sub + Copy,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::{block_on, cancel_after, Yielding};
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn successive_differences() {
+        block_on(async {
+            let iter = from_iter(vec![1, 4, 9, 16]).delta();
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec![3, 5, 7]);
+
+            let out: Vec<i32> = from_iter(vec![1]).delta().collect().await;
+            assert!(out.is_empty());
+        })
+    }
+
+    #[test]
+    fn cancelled_next_keeps_items() {
+        let mut iter = Yielding::new(3).delta();
+        cancel_after(iter.next(), 2);
+        block_on(async {
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec![1, 1]);
+        })
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{ExactSizeIterator, FusedIterator, Iterator, LendingIterator};
+
+/// An iterator which is one of two different iterator types.
+///
+/// This makes it possible to return one of two differently-typed pipelines
+/// from a function without boxing them, as long as both yield the same items.
+#[derive(Debug, Clone)]
+pub enum EitherIter<L, R> {
+    /// The left iterator.
+    Left(L),
+    /// The right iterator.
+    Right(R),
+}
+
+impl<L, R> Iterator for EitherIter<L, R>
+where
+    L: Iterator,
+    R: Iterator<Item = L::Item>,
+{
+    type Item = L::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            EitherIter::Left(iter) => iter.next().await,
+            EitherIter::Right(iter) => iter.next().await,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            EitherIter::Left(iter) => iter.size_hint(),
+            EitherIter::Right(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<L, R> ExactSizeIterator for EitherIter<L, R>
+where
+    L: ExactSizeIterator,
+    R: ExactSizeIterator<Item = L::Item>,
+{
+    fn len(&self) -> usize {
+        match self {
+            EitherIter::Left(iter) => iter.len(),
+            EitherIter::Right(iter) => iter.len(),
+        }
+    }
+}
+
+impl<L, R> FusedIterator for EitherIter<L, R>
+where
+    L: FusedIterator,
+    R: FusedIterator<Item = L::Item>,
+{
+}
+
+/// Lent items are wrapped in the arm they came from, since the borrowed item
+/// types of two different lending iterators can't be unified.
+impl<L, R> LendingIterator for EitherIter<L, R>
+where
+    L: LendingIterator,
+    R: LendingIterator,
+{
+    type Item<'a>
+        = EitherIter<L::Item<'a>, R::Item<'a>>
+    where
+        Self: 'a;
+
+    async fn next(&mut self) -> Option<Self::Item<'_>> {
+        match self {
+            EitherIter::Left(iter) => LendingIterator::next(iter).await.map(EitherIter::Left),
+            EitherIter::Right(iter) => LendingIterator::next(iter).await.map(EitherIter::Right),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EitherIter;
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    fn pipeline(reverse: bool) -> impl Iterator<Item = u8> {
+        if reverse {
+            EitherIter::Left(from_iter((0..3).rev()))
+        } else {
+            EitherIter::Right(from_iter(0..3).map(async |x| x * 10))
+        }
+    }
+
+    #[test]
+    fn lending() {
+        use crate::LendingIterator;
+        block_on(async {
+            let mut iter =
+                EitherIter::<_, crate::Lend<crate::FromIter<core::ops::Range<u8>>>>::Left(
+                    from_iter(0..2).lend_mut(),
+                );
+            match LendingIterator::next(&mut iter).await {
+                Some(EitherIter::Left((_, item))) => assert_eq!(item, 0),
+                _ => panic!("expected an item from the left arm"),
+            }
+
+            let mut iter =
+                EitherIter::<crate::Lend<crate::FromIter<core::ops::Range<u8>>>, _>::Right(
+                    from_iter(5..7).lend_mut(),
+                );
+            match LendingIterator::next(&mut iter).await {
+                Some(EitherIter::Right((_, item))) => assert_eq!(item, 5),
+                _ => panic!("expected an item from the right arm"),
+            }
+        })
+    }
+
+    #[test]
+    fn exact_size() {
+        use crate::ExactSizeIterator;
+        block_on(async {
+            let mut iter =
+                EitherIter::<_, crate::FromIter<core::ops::Range<u8>>>::Left(from_iter(0..3u8));
+            assert_eq!(iter.len(), 3);
+            iter.next().await;
+            assert_eq!(iter.len(), 2);
+
+            let iter = EitherIter::<crate::FromIter<core::ops::Range<u8>>, _>::Right(
+                from_iter(0..4u8).map(async |x| x + 1),
+            );
+            assert_eq!(iter.len(), 4);
+        })
+    }
+
+    #[test]
+    fn either_branch() {
+        block_on(async {
+            let out: Vec<_> = pipeline(true).collect().await;
+            assert_eq!(out, vec![2, 1, 0]);
+            let out: Vec<_> = pipeline(false).collect().await;
+            assert_eq!(out, vec![0, 10, 20]);
+        })
+    }
+}
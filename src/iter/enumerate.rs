@@ -0,0 +1,57 @@
+use crate::{ExactSizeIterator, FusedIterator, Iterator};
+
+/// An iterator that yields the current count and the element during
+/// iteration.
+#[derive(Debug, Clone)]
+pub struct Enumerate<I> {
+    stream: I,
+    count: usize,
+}
+
+impl<I> Enumerate<I> {
+    pub(crate) fn new(stream: I, start: usize) -> Self {
+        Self {
+            stream,
+            count: start,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Enumerate<I> {
+    type Item = (usize, I::Item);
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await?;
+        let index = self.count;
+        self.count += 1;
+        Some((index, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Enumerate<I> {}
+
+impl<I: FusedIterator> FusedIterator for Enumerate<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn custom_offset() {
+        block_on(async {
+            let out: Vec<_> = from_iter(vec!['a', 'b', 'c'])
+                .enumerate_from(100)
+                .collect()
+                .await;
+            assert_eq!(out, vec![(100, 'a'), (101, 'b'), (102, 'c')]);
+
+            let out: Vec<_> = from_iter(vec!['a', 'b']).enumerate().collect().await;
+            assert_eq!(out, vec![(0, 'a'), (1, 'b')]);
+        })
+    }
+}
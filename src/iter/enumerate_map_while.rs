@@ -0,0 +1,96 @@
+use crate::{FusedIterator, Iterator};
+
+/// An iterator that maps the elements of another iterator along with their
+/// index, until the mapper returns `None`.
+#[derive(Debug)]
+pub struct EnumerateMapWhile<I, F> {
+    stream: I,
+    f: F,
+    index: usize,
+    done: bool,
+}
+
+impl<I, F> EnumerateMapWhile<I, F> {
+    pub(crate) fn new(stream: I, f: F) -> Self {
+        Self {
+            stream,
+            f,
+            index: 0,
+            done: false,
+        }
+    }
+}
+
+impl<I, F, B> Iterator for EnumerateMapWhile<I, F>
+where
+    I: Iterator,
+    F: AsyncFnMut(usize, I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let Some(item) = self.stream.next().await else {
+            self.done = true;
+            return None;
+        };
+        let index = self.index;
+        self.index += 1;
+        let out = (self.f)(index, item).await;
+        self.done = out.is_none();
+        out
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
+
+impl<I, F, B> FusedIterator for EnumerateMapWhile<I, F>
+where
+    I: Iterator,
+    F: AsyncFnMut(usize, I::Item) -> Option<B>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::{block_on, Resuming};
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn stops_on_none() {
+        block_on(async {
+            let mut iter =
+                from_iter(vec!["a", "b", "c", "d"]).enumerate_map_while(|i, s| async move {
+                    if i < 2 {
+                        Some(format!("{}{}", s, i))
+                    } else {
+                        None
+                    }
+                });
+            assert_eq!(iter.next().await.as_deref(), Some("a0"));
+            assert_eq!(iter.next().await.as_deref(), Some("b1"));
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.next().await, None);
+        })
+    }
+
+    #[test]
+    fn fused_on_source_end() {
+        block_on(async {
+            let mut source = Resuming::new(vec![Some(1), None, Some(2)]);
+            let mut iter = (&mut source).enumerate_map_while(async |i, x| Some(i + x));
+            assert_eq!(iter.next().await, Some(1));
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.next().await, None);
+            assert_eq!(source.polls, 2);
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{FusedIterator, Iterator};
+
+/// An iterator that attaches context to the errors of an iterator of results.
+#[derive(Debug)]
+pub struct ErrContext<I, C> {
+    stream: I,
+    ctx: C,
+}
+
+impl<I, C> ErrContext<I, C> {
+    pub(crate) fn new(stream: I, ctx: C) -> Self {
+        Self { stream, ctx }
+    }
+}
+
+impl<I, T, E, C> Iterator for ErrContext<I, C>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Clone,
+{
+    type Item = Result<T, (C, E)>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await?;
+        Some(item.map_err(|err| (self.ctx.clone(), err)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<I, T, E, C> FusedIterator for ErrContext<I, C>
+where
+    I: FusedIterator<Item = Result<T, E>>,
+    C: Clone,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn attaches_request_id() {
+        block_on(async {
+            let iter = from_iter(vec![Ok(1), Err("timeout"), Ok(2)]);
+            let out: Vec<_> = iter.context("req-42").collect().await;
+            assert_eq!(out, vec![Ok(1), Err(("req-42", "timeout")), Ok(2)]);
+        })
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{FusedIterator, Iterator};
+use core::marker::PhantomData;
+
+/// An iterator that converts the error type of an iterator of results.
+#[derive(Debug)]
+pub struct ErrInto<I, E> {
+    stream: I,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<I, E> ErrInto<I, E> {
+    pub(crate) fn new(stream: I) -> Self {
+        Self {
+            stream,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I, T, E, E2> Iterator for ErrInto<I, E2>
+where
+    I: Iterator<Item = Result<T, E>>,
+    E2: From<E>,
+{
+    type Item = Result<T, E2>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await?;
+        Some(item.map_err(E2::from))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<I, T, E, E2> FusedIterator for ErrInto<I, E2>
+where
+    I: FusedIterator<Item = Result<T, E>>,
+    E2: From<E>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[derive(Debug, PartialEq)]
+    struct ParseError(u8);
+
+    #[derive(Debug, PartialEq)]
+    enum AppError {
+        Parse(ParseError),
+    }
+
+    impl From<ParseError> for AppError {
+        fn from(err: ParseError) -> Self {
+            AppError::Parse(err)
+        }
+    }
+
+    #[test]
+    fn converts_errors() {
+        block_on(async {
+            let iter = from_iter(vec![Ok(1), Err(ParseError(7))]);
+            let out: Vec<Result<i32, AppError>> = iter.err_into().collect().await;
+            assert_eq!(out, vec![Ok(1), Err(AppError::Parse(ParseError(7)))]);
+        })
+    }
+}
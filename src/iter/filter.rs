@@ -0,0 +1,63 @@
+use crate::Iterator;
+
+/// An iterator that filters the elements of another iterator with a predicate.
+#[derive(Debug)]
+pub struct Filter<I, P> {
+    stream: I,
+    predicate: P,
+}
+
+impl<I, P> Filter<I, P> {
+    pub(crate) fn new(stream: I, predicate: P) -> Self {
+        Self { stream, predicate }
+    }
+}
+
+impl<I, P> Iterator for Filter<I, P>
+where
+    I: Iterator,
+    P: AsyncFnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.stream.next().await?;
+            if (self.predicate)(&item).await {
+                return Some(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn map_skips_rejected_items() {
+        block_on(async {
+            let mut checked = Vec::new();
+            let mut mapped = Vec::new();
+            let out: Vec<_> = from_iter(1..=6)
+                .filter(async |x: &u8| {
+                    checked.push(*x);
+                    x.is_multiple_of(2)
+                })
+                .map(async |x| {
+                    mapped.push(x);
+                    x * 10
+                })
+                .collect()
+                .await;
+            assert_eq!(out, vec![20, 40, 60]);
+            assert_eq!(checked, vec![1, 2, 3, 4, 5, 6]);
+            assert_eq!(mapped, vec![2, 4, 6]);
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use crate::Iterator;
+
+/// An iterator that filters the elements of another iterator with a
+/// synchronous predicate.
+#[derive(Debug)]
+pub struct FilterSync<I, P> {
+    stream: I,
+    predicate: P,
+}
+
+impl<I, P> FilterSync<I, P> {
+    pub(crate) fn new(stream: I, predicate: P) -> Self {
+        Self { stream, predicate }
+    }
+}
+
+impl<I, P> Iterator for FilterSync<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.stream.next().await?;
+            if (self.predicate)(&item) {
+                return Some(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn sync_predicate() {
+        block_on(async {
+            let out: Vec<_> = from_iter(0..6).filter_sync(|x| x % 3 == 0).collect().await;
+            assert_eq!(out, vec![0, 3]);
+        })
+    }
+}
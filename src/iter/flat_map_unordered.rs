@@ -0,0 +1,126 @@
+use super::flatten_unordered::InFlight;
+use crate::{IntoIterator, Iterator};
+use core::fmt;
+use core::num::NonZeroUsize;
+
+/// An iterator that maps each item of another iterator to an inner iterator,
+/// polling several inner iterators concurrently.
+///
+/// In-flight inner iterators are owned by this adapter, so dropping it
+/// cancels all outstanding work.
+pub struct FlatMapUnordered<I, U: IntoIterator, F> {
+    stream: I,
+    f: F,
+    in_flight: InFlight<U>,
+}
+
+impl<I, U, F> fmt::Debug for FlatMapUnordered<I, U, F>
+where
+    I: fmt::Debug,
+    U: IntoIterator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlatMapUnordered")
+            .field("stream", &self.stream)
+            .field("in_flight", &self.in_flight)
+            .finish()
+    }
+}
+
+impl<I, U: IntoIterator + 'static, F> FlatMapUnordered<I, U, F> {
+    pub(crate) fn new(stream: I, limit: NonZeroUsize, f: F) -> Self {
+        Self {
+            stream,
+            f,
+            in_flight: InFlight::new(limit.get()),
+        }
+    }
+}
+
+impl<I, U, F> Iterator for FlatMapUnordered<I, U, F>
+where
+    I: Iterator,
+    U: IntoIterator + 'static,
+    F: FnMut(I::Item) -> U,
+{
+    type Item = U::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        self.in_flight.next(&mut self.stream, &mut self.f).await
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.in_flight.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+    use core::cell::Cell;
+    use core::future::poll_fn;
+    use core::num::NonZeroUsize;
+    use core::task::Poll;
+    use std::rc::Rc;
+
+    /// Yields the rows of a partition, returning `Pending` `delay` times
+    /// before each one, and tracks how many partitions are open at once.
+    struct Partition {
+        rows: std::vec::IntoIter<u32>,
+        delay: usize,
+        open: Rc<Cell<usize>>,
+    }
+
+    impl Drop for Partition {
+        fn drop(&mut self) {
+            self.open.set(self.open.get() - 1);
+        }
+    }
+
+    impl Iterator for Partition {
+        type Item = u32;
+
+        async fn next(&mut self) -> Option<u32> {
+            for _ in 0..self.delay {
+                let mut yielded = false;
+                poll_fn(|cx| {
+                    if core::mem::replace(&mut yielded, true) {
+                        Poll::Ready(())
+                    } else {
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    }
+                })
+                .await;
+            }
+            self.rows.next()
+        }
+    }
+
+    #[test]
+    fn drives_partitions_concurrently() {
+        block_on(async {
+            let open = Rc::new(Cell::new(0));
+            let max_open = Cell::new(0);
+            let partitions = vec![(0..3, 5), (10..11, 0), (20..20, 1), (30..34, 2)];
+            let limit = NonZeroUsize::new(2).unwrap();
+            let mut out: Vec<_> = from_iter(partitions)
+                .flat_map_unordered(limit, |(rows, delay)| {
+                    open.set(open.get() + 1);
+                    max_open.set(max_open.get().max(open.get()));
+                    Partition {
+                        rows: rows.collect::<Vec<_>>().into_iter(),
+                        delay,
+                        open: open.clone(),
+                    }
+                })
+                .collect()
+                .await;
+            out.sort_unstable();
+            assert_eq!(out, vec![0, 1, 2, 10, 30, 31, 32, 33]);
+            assert_eq!(max_open.get(), 2);
+            assert_eq!(open.get(), 0);
+        })
+    }
+}
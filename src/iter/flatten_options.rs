@@ -0,0 +1,50 @@
+use crate::{FusedIterator, Iterator};
+
+/// An iterator that yields the values of an iterator of options, skipping
+/// `None`s.
+#[derive(Debug)]
+pub struct FlattenOptions<I> {
+    stream: I,
+}
+
+impl<I> FlattenOptions<I> {
+    pub(crate) fn new(stream: I) -> Self {
+        Self { stream }
+    }
+}
+
+impl<I, T> Iterator for FlattenOptions<I>
+where
+    I: Iterator<Item = Option<T>>,
+{
+    type Item = T;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.stream.next().await? {
+                return Some(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
+
+impl<I, T> FusedIterator for FlattenOptions<I> where I: FusedIterator<Item = Option<T>> {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn skips_none() {
+        block_on(async {
+            let iter = from_iter(vec![Some(1), None, Some(3)]);
+            let out: Vec<_> = iter.flatten_options().collect().await;
+            assert_eq!(out, vec![1, 3]);
+        })
+    }
+}
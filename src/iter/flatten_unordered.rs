@@ -0,0 +1,296 @@
+use crate::{IntoIterator, Iterator};
+use core::cell::Cell;
+use core::fmt;
+use core::future::{poll_fn, Future};
+use core::pin::Pin;
+use core::task::Poll;
+use std::boxed::Box;
+use std::rc::Rc;
+use std::vec::Vec;
+
+/// An inner iterator being driven by an unordered flattening adapter.
+///
+/// The driver is a future which owns the inner iterator and pulls it to
+/// completion, handing every item over through `slot`. The future returned by
+/// `Iterator::next` borrows its iterator and can't be named, so the driver is
+/// boxed; it lives as long as the inner iterator does, which keeps the cost to
+/// one allocation per inner iterator rather than one per item.
+struct Driver<T> {
+    slot: Rc<Cell<Option<T>>>,
+    future: Pin<Box<dyn Future<Output = ()>>>,
+}
+
+impl<T: 'static> Driver<T> {
+    fn new<U>(inner: U) -> Self
+    where
+        U: IntoIterator<Item = T> + 'static,
+    {
+        let slot = Rc::new(Cell::new(None));
+        let handoff = slot.clone();
+        let future = Box::pin(async move {
+            let mut iter = inner.into_iter().await;
+            while let Some(item) = iter.next().await {
+                handoff.set(Some(item));
+                // Suspend once so the adapter can take the item before the
+                // next one is pulled. The adapter empties the slot in the same
+                // poll that observes it, so no wake-up is needed.
+                let mut yielded = false;
+                poll_fn(|_| {
+                    if core::mem::replace(&mut yielded, true) {
+                        Poll::Ready(())
+                    } else {
+                        Poll::Pending
+                    }
+                })
+                .await;
+            }
+        });
+        Self { slot, future }
+    }
+}
+
+/// The set of inner iterators being driven concurrently by an unordered
+/// flattening adapter.
+pub(crate) struct InFlight<U: IntoIterator> {
+    limit: usize,
+    done: bool,
+    drivers: Vec<Driver<U::Item>>,
+    /// The driver to poll first, so that ready drivers take turns.
+    cursor: usize,
+}
+
+impl<U: IntoIterator + 'static> InFlight<U> {
+    pub(crate) fn new(limit: usize) -> Self {
+        assert!(limit != 0, "`limit` must be greater than zero");
+        Self {
+            limit,
+            done: false,
+            drivers: Vec::new(),
+            cursor: 0,
+        }
+    }
+
+    /// Yields the next ready item, topping the in-flight set up from
+    /// `stream` with inner iterators built by `expand`.
+    pub(crate) async fn next<I, F>(&mut self, stream: &mut I, mut expand: F) -> Option<U::Item>
+    where
+        I: Iterator,
+        F: FnMut(I::Item) -> U,
+    {
+        loop {
+            while !self.done && self.drivers.len() < self.limit {
+                match stream.next().await {
+                    Some(inner) => self.drivers.push(Driver::new(expand(inner))),
+                    None => self.done = true,
+                }
+            }
+            if self.drivers.is_empty() {
+                return None;
+            }
+
+            let drivers = &mut self.drivers;
+            let cursor = &mut self.cursor;
+            let item = poll_fn(|cx| {
+                let len = drivers.len();
+                for offset in 0..len {
+                    let index = (*cursor + offset) % len;
+                    let driver = &mut drivers[index];
+                    if driver.future.as_mut().poll(cx).is_ready() {
+                        drop(drivers.swap_remove(index));
+                        *cursor = index;
+                        return Poll::Ready(None);
+                    }
+                    if let Some(item) = driver.slot.take() {
+                        *cursor = index + 1;
+                        return Poll::Ready(Some(item));
+                    }
+                }
+                Poll::Pending
+            })
+            .await;
+            if item.is_some() {
+                return item;
+            }
+        }
+    }
+
+    pub(crate) fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done && self.drivers.is_empty() {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+impl<U: IntoIterator> fmt::Debug for InFlight<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InFlight")
+            .field("limit", &self.limit)
+            .field("done", &self.done)
+            .field("len", &self.drivers.len())
+            .finish()
+    }
+}
+
+/// An iterator that flattens an iterator of iterators, polling several inner
+/// iterators concurrently.
+///
+/// In-flight inner iterators are owned by this adapter, so dropping it (or a
+/// future consuming it, such as `collect`) cancels all outstanding work.
+pub struct FlattenUnordered<I>
+where
+    I: Iterator,
+    I::Item: IntoIterator,
+{
+    stream: I,
+    in_flight: InFlight<I::Item>,
+}
+
+impl<I> fmt::Debug for FlattenUnordered<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: IntoIterator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlattenUnordered")
+            .field("stream", &self.stream)
+            .field("in_flight", &self.in_flight)
+            .finish()
+    }
+}
+
+impl<I> FlattenUnordered<I>
+where
+    I: Iterator,
+    I::Item: IntoIterator + 'static,
+{
+    pub(crate) fn new(stream: I, limit: usize) -> Self {
+        Self {
+            stream,
+            in_flight: InFlight::new(limit),
+        }
+    }
+}
+
+impl<I> Iterator for FlattenUnordered<I>
+where
+    I: Iterator,
+    I::Item: IntoIterator + 'static,
+{
+    type Item = <I::Item as IntoIterator>::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        self.in_flight.next(&mut self.stream, |inner| inner).await
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.in_flight.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+    use core::future::poll_fn;
+    use core::task::Poll;
+
+    /// Yields its items, returning `Pending` `delay` times before each one.
+    struct Delayed {
+        items: std::vec::IntoIter<u32>,
+        delay: usize,
+    }
+
+    impl Iterator for Delayed {
+        type Item = u32;
+
+        async fn next(&mut self) -> Option<u32> {
+            for _ in 0..self.delay {
+                let mut yielded = false;
+                poll_fn(|cx| {
+                    if yielded {
+                        Poll::Ready(())
+                    } else {
+                        yielded = true;
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    }
+                })
+                .await;
+            }
+            self.items.next()
+        }
+    }
+
+    #[test]
+    fn completion_order() {
+        block_on(async {
+            let slow = Delayed {
+                items: vec![1, 2].into_iter(),
+                delay: 10,
+            };
+            let fast = Delayed {
+                items: vec![10, 20].into_iter(),
+                delay: 0,
+            };
+            let out: Vec<_> = from_iter(vec![slow, fast])
+                .flatten_unordered(2)
+                .collect()
+                .await;
+            assert_eq!(out, vec![10, 20, 1, 2]);
+        })
+    }
+
+    #[test]
+    fn ready_inner_iterators_take_turns() {
+        block_on(async {
+            let out: Vec<_> = from_iter(vec![from_iter(0..3), from_iter(10..13)])
+                .flatten_unordered(2)
+                .collect()
+                .await;
+            assert_eq!(out, vec![0, 10, 1, 11, 2, 12]);
+        })
+    }
+
+    #[test]
+    fn dropping_collect_cancels_inner() {
+        use core::future::Future;
+        use core::pin::pin;
+        use core::task::{Context, Waker};
+        use std::rc::Rc;
+
+        /// Never yields, and records when it is dropped.
+        struct Stuck {
+            _guard: Rc<()>,
+        }
+
+        impl Iterator for Stuck {
+            type Item = u8;
+
+            async fn next(&mut self) -> Option<u8> {
+                core::future::pending().await
+            }
+        }
+
+        let guard = Rc::new(());
+        let inner = vec![
+            Stuck {
+                _guard: guard.clone(),
+            },
+            Stuck {
+                _guard: guard.clone(),
+            },
+        ];
+        {
+            let fut = from_iter(inner).flatten_unordered(2).collect::<Vec<_>>();
+            let mut fut = pin!(fut);
+            let mut cx = Context::from_waker(Waker::noop());
+            for _ in 0..3 {
+                assert!(fut.as_mut().poll(&mut cx).is_pending());
+            }
+            assert_eq!(Rc::strong_count(&guard), 3);
+        }
+        assert_eq!(Rc::strong_count(&guard), 1);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{FusedIterator, Iterator};
+use std::vec::Vec;
+
+/// An iterator that re-frames byte chunks into newline-delimited records.
+#[derive(Debug)]
+pub struct FrameLines<I> {
+    stream: I,
+    buf: Vec<u8>,
+    /// Where the next record starts in `buf`. Bytes before it have already
+    /// been yielded and are dropped once per incoming chunk.
+    start: usize,
+    /// How much of `buf` is known not to contain a newline.
+    searched: usize,
+    done: bool,
+}
+
+impl<I> FrameLines<I> {
+    pub(crate) fn new(stream: I) -> Self {
+        Self {
+            stream,
+            buf: Vec::new(),
+            start: 0,
+            searched: 0,
+            done: false,
+        }
+    }
+}
+
+impl<I> Iterator for FrameLines<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type Item = Vec<u8>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pos) = self.buf[self.searched..].iter().position(|b| *b == b'\n') {
+                let end = self.searched + pos;
+                let record = self.buf[self.start..end].to_vec();
+                self.start = end + 1;
+                self.searched = self.start;
+                return Some(record);
+            }
+            self.searched = self.buf.len();
+            if self.done {
+                return None;
+            }
+            match self.stream.next().await {
+                Some(chunk) => {
+                    self.buf.drain(..self.start);
+                    self.searched -= self.start;
+                    self.start = 0;
+                    self.buf.extend_from_slice(chunk.as_ref());
+                }
+                None => {
+                    self.done = true;
+                    let rest = self.buf.split_off(self.start);
+                    self.buf.clear();
+                    self.start = 0;
+                    self.searched = 0;
+                    return if rest.is_empty() { None } else { Some(rest) };
+                }
+            }
+        }
+    }
+}
+
+impl<I> FusedIterator for FrameLines<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn across_chunks() {
+        block_on(async {
+            let chunks = vec!["{\"a\":1}\n{\"b", "\":2}\n"];
+            let out: Vec<_> = from_iter(chunks).frame_lines().collect().await;
+            assert_eq!(out, vec![b"{\"a\":1}".to_vec(), b"{\"b\":2}".to_vec()]);
+        })
+    }
+
+    #[test]
+    fn trailing_record() {
+        block_on(async {
+            let chunks = vec![&b"a\n\nb"[..], b"c"];
+            let out: Vec<_> = from_iter(chunks).frame_lines().collect().await;
+            assert_eq!(out, vec![b"a".to_vec(), vec![], b"bc".to_vec()]);
+        })
+    }
+
+    #[test]
+    fn many_records_in_one_chunk() {
+        block_on(async {
+            let chunk = "a\nbb\n\nccc\nd".to_string();
+            let out: Vec<_> = from_iter(vec![chunk, "d\n".to_string()])
+                .frame_lines()
+                .collect()
+                .await;
+            let expected: Vec<&[u8]> = vec![b"a", b"bb", b"", b"ccc", b"dd"];
+            assert_eq!(out, expected);
+        })
+    }
+}
@@ -0,0 +1,121 @@
+use crate::Iterator;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// An iterator that records how many items were consumed from it, and
+/// whether it was exhausted before being dropped.
+#[derive(Debug)]
+pub struct Instrumented<I> {
+    stream: I,
+    stats: Stats,
+}
+
+impl<I> Instrumented<I> {
+    pub(crate) fn new(stream: I) -> Self {
+        Self {
+            stream,
+            stats: Stats::default(),
+        }
+    }
+
+    /// Returns a handle to the statistics of this iterator.
+    ///
+    /// The handle remains valid after the iterator has been dropped.
+    pub fn stats(&self) -> Stats {
+        self.stats.clone()
+    }
+}
+
+impl<I: Iterator> Iterator for Instrumented<I> {
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await;
+        if item.is_some() {
+            self.stats.inner.consumed.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.stats.inner.completed.store(true, Ordering::Relaxed);
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<I> Drop for Instrumented<I> {
+    fn drop(&mut self) {
+        self.stats.inner.dropped.store(true, Ordering::Release);
+    }
+}
+
+/// A handle to the statistics of an [`Instrumented`] iterator.
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    inner: Arc<StatsInner>,
+}
+
+#[derive(Debug, Default)]
+struct StatsInner {
+    consumed: AtomicUsize,
+    completed: AtomicBool,
+    dropped: AtomicBool,
+}
+
+impl Stats {
+    /// Returns the number of items yielded by the iterator so far.
+    pub fn consumed(&self) -> usize {
+        self.inner.consumed.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the iterator has returned `None`.
+    pub fn completed(&self) -> bool {
+        self.inner.completed.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the iterator has been dropped.
+    pub fn dropped(&self) -> bool {
+        self.inner.dropped.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if the iterator was dropped before it was exhausted.
+    pub fn abandoned(&self) -> bool {
+        self.dropped() && !self.completed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn full_consumption() {
+        block_on(async {
+            let iter = from_iter(0..3).instrumented();
+            let stats = iter.stats();
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out.len(), 3);
+            assert_eq!(stats.consumed(), 3);
+            assert!(stats.completed());
+            assert!(stats.dropped());
+            assert!(!stats.abandoned());
+        })
+    }
+
+    #[test]
+    fn early_drop() {
+        block_on(async {
+            let mut iter = from_iter(0..10).instrumented();
+            let stats = iter.stats();
+            iter.next().await;
+            iter.next().await;
+            assert_eq!(stats.consumed(), 2);
+            assert!(!stats.dropped());
+            drop(iter);
+            assert_eq!(stats.consumed(), 2);
+            assert!(stats.abandoned());
+        })
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{FusedIterator, Iterator};
+
+/// An iterator adapter that places a separator between all elements.
+#[derive(Debug)]
+pub struct Intersperse<I: Iterator> {
+    stream: I,
+    separator: I::Item,
+    started: bool,
+    next_item: Option<I::Item>,
+}
+
+impl<I: Iterator> Intersperse<I> {
+    pub(crate) fn new(stream: I, separator: I::Item) -> Self {
+        Self {
+            stream,
+            separator,
+            started: false,
+            next_item: None,
+        }
+    }
+}
+
+impl<I> Iterator for Intersperse<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return self.stream.next().await;
+        }
+        if let Some(item) = self.next_item.take() {
+            return Some(item);
+        }
+        let item = self.stream.next().await?;
+        self.next_item = Some(item);
+        Some(self.separator.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every remaining source item is preceded by a separator, except for
+        // the very first item when iteration hasn't started yet.
+        let first = usize::from(!self.started);
+        let buffered = usize::from(self.next_item.is_some());
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower
+            .saturating_sub(first)
+            .saturating_add(lower)
+            .saturating_add(buffered);
+        let upper = upper.and_then(|upper| {
+            upper
+                .saturating_sub(first)
+                .checked_add(upper)?
+                .checked_add(buffered)
+        });
+        (lower, upper)
+    }
+}
+
+impl<I> FusedIterator for Intersperse<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn size_hint() {
+        block_on(async {
+            let mut iter = from_iter(vec![1, 2, 3]).intersperse(0);
+            assert_eq!(iter.size_hint(), (5, Some(5)));
+            let mut remaining = 5;
+            while iter.next().await.is_some() {
+                remaining -= 1;
+                assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+            }
+            assert_eq!(remaining, 0);
+
+            let iter = from_iter(Vec::<u8>::new()).intersperse(0);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+        })
+    }
+
+    #[test]
+    fn separators() {
+        block_on(async {
+            let out: Vec<_> = from_iter(vec![1, 2, 3]).intersperse(0).collect().await;
+            assert_eq!(out, vec![1, 0, 2, 0, 3]);
+        })
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{FusedIterator, Iterator};
+use core::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::vec::Vec;
+
+/// Merges many sorted iterators into a single sorted iterator.
+///
+/// Each iterator must yield its items in ascending order. Equal items are
+/// yielded in the order of the iterators they came from, so the merge is
+/// stable.
+pub fn kmerge<I>(iters: impl core::iter::IntoIterator<Item = I>) -> KMerge<I>
+where
+    I: Iterator,
+    I::Item: Ord,
+{
+    let iters: Vec<I> = iters.into_iter().collect();
+    KMerge {
+        heap: BinaryHeap::with_capacity(iters.len()),
+        iters,
+        primed: 0,
+    }
+}
+
+/// An iterator that merges many sorted iterators.
+///
+/// This `struct` is created by the [`kmerge`] function.
+#[derive(Debug)]
+pub struct KMerge<I: Iterator> {
+    iters: Vec<I>,
+    /// The head of each non-exhausted iterator, tagged with its index.
+    heap: BinaryHeap<Reverse<(I::Item, usize)>>,
+    /// The number of iterators whose head has been read into `heap`.
+    primed: usize,
+}
+
+impl<I> Iterator for KMerge<I>
+where
+    I: Iterator,
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        while self.primed < self.iters.len() {
+            let index = self.primed;
+            if let Some(item) = self.iters[index].next().await {
+                self.heap.push(Reverse((item, index)));
+            }
+            self.primed += 1;
+        }
+        // Keep the smallest head in the heap until its replacement has been
+        // read, so a cancelled call doesn't lose it.
+        let index = (self.heap.peek()?.0).1;
+        let next = self.iters[index].next().await;
+        let Reverse((item, _)) = self.heap.pop()?;
+        if let Some(next) = next {
+            self.heap.push(Reverse((next, index)));
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.heap.len();
+        self.iters.iter().map(Iterator::size_hint).fold(
+            (buffered, Some(buffered)),
+            |(lower, upper), (l, u)| {
+                let upper = match (upper, u) {
+                    (Some(a), Some(b)) => a.checked_add(b),
+                    _ => None,
+                };
+                (lower.saturating_add(l), upper)
+            },
+        )
+    }
+}
+
+impl<I> FusedIterator for KMerge<I>
+where
+    I: FusedIterator,
+    I::Item: Ord,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use super::kmerge;
+    use crate::test::{block_on, cancel_after, Yielding};
+    use crate::{from_iter, Iterator};
+    use core::cmp::Ordering;
+
+    /// An event which is ordered by its timestamp only.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Event(u32, char);
+
+    impl PartialOrd for Event {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Event {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    fn shard(tag: char, times: &[u32]) -> Vec<Event> {
+        times.iter().map(|&t| Event(t, tag)).collect()
+    }
+
+    #[test]
+    fn merges_in_order() {
+        block_on(async {
+            let shards = vec![
+                from_iter(shard('d', &[1, 4, 9])),
+                from_iter(shard('x', &[])),
+                from_iter(shard('b', &[2, 4])),
+                from_iter(shard('a', &[0, 1, 4, 5, 10])),
+            ];
+            let iter = kmerge(shards);
+            assert_eq!(iter.size_hint(), (10, Some(10)));
+            let out: Vec<_> = iter.collect().await;
+            let expected = vec![
+                Event(0, 'a'),
+                Event(1, 'd'),
+                Event(1, 'a'),
+                Event(2, 'b'),
+                Event(4, 'd'),
+                Event(4, 'b'),
+                Event(4, 'a'),
+                Event(5, 'a'),
+                Event(9, 'd'),
+                Event(10, 'a'),
+            ];
+            // `Event`'s `PartialEq` compares tags too, so this checks that
+            // ties were broken by source index.
+            assert_eq!(out, expected);
+        })
+    }
+
+    #[test]
+    fn cancelled_next_keeps_items() {
+        let mut iter = kmerge(vec![Yielding::new(2), Yielding::new(2)]);
+        cancel_after(iter.next(), 2);
+        block_on(async {
+            assert_eq!(iter.next().await, Some(0));
+            cancel_after(iter.next(), 1);
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec![0, 1, 1]);
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Iterator, LendingIterator};
+use crate::{FusedIterator, FusedLendingIterator, Iterator, LendingIterator};
 
 /// The iterator returned from `AsyncIterator::lend`.
 #[derive(Debug)]
@@ -8,10 +8,16 @@ impl<I: Iterator> Lend<I> {
     pub(crate) fn new(i: I) -> Self {
         Self(i)
     }
+
+    /// Consumes the adapter, returning the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.0
+    }
 }
 
 impl<I: Iterator> LendingIterator for Lend<I> {
-    type Item<'a> = (&'a I, I::Item)
+    type Item<'a>
+        = (&'a I, I::Item)
     where
         Self: 'a;
 
@@ -19,4 +25,29 @@ impl<I: Iterator> LendingIterator for Lend<I> {
         let item = self.0.next().await;
         item.map(move |item| (&self.0, item))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I: FusedIterator> FusedLendingIterator for Lend<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator, LendingIterator};
+
+    #[test]
+    fn into_inner_resumes() {
+        block_on(async {
+            let mut lend = from_iter(0..3).lend();
+            let (iter, item) = lend.next().await.unwrap();
+            assert_eq!((iter.size_hint(), item), ((2, Some(2)), 0));
+            assert_eq!(lend.size_hint(), (2, Some(2)));
+
+            let rest: Vec<_> = lend.into_inner().collect().await;
+            assert_eq!(rest, vec![1, 2]);
+        })
+    }
 }
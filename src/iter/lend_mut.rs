@@ -1,5 +1,4 @@
-use crate::Iterator;
-use crate::LendingIterator;
+use crate::{FusedIterator, FusedLendingIterator, Iterator, LendingIterator};
 
 /// The iterator returned from `AsyncIterator::lend`.
 #[derive(Debug)]
@@ -9,10 +8,16 @@ impl<I: Iterator> LendMut<I> {
     pub(crate) fn new(i: I) -> Self {
         Self(i)
     }
+
+    /// Consumes the adapter, returning the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.0
+    }
 }
 
 impl<I: Iterator> LendingIterator for LendMut<I> {
-    type Item<'a> = (&'a mut I, I::Item)
+    type Item<'a>
+        = (&'a mut I, I::Item)
     where
         Self: 'a;
 
@@ -20,4 +25,39 @@ impl<I: Iterator> LendingIterator for LendMut<I> {
         let item = self.0.next().await;
         item.map(move |item| (&mut self.0, item))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I: FusedIterator> FusedLendingIterator for LendMut<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator, LendingIterator};
+
+    #[test]
+    fn stop_on_state_then_resume() {
+        block_on(async {
+            let mut lend = from_iter(0..6).lend_mut();
+            assert_eq!(lend.size_hint(), (6, Some(6)));
+            let mut seen = Vec::new();
+            while let Some((iter, item)) = lend.next().await {
+                seen.push(item);
+                if item == 1 {
+                    // Skip an item through the lent handle.
+                    iter.next().await;
+                }
+                if iter.size_hint().0 <= 2 {
+                    break;
+                }
+            }
+            assert_eq!(seen, vec![0, 1, 3]);
+
+            let rest: Vec<_> = lend.into_inner().collect().await;
+            assert_eq!(rest, vec![4, 5]);
+        })
+    }
 }
@@ -0,0 +1,71 @@
+use crate::{FusedIterator, Iterator};
+
+/// An iterator that yields a snapshot of another iterator's state alongside
+/// each of its items.
+#[derive(Debug)]
+pub struct LendWith<I, F> {
+    stream: I,
+    f: F,
+}
+
+impl<I, F> LendWith<I, F> {
+    pub(crate) fn new(stream: I, f: F) -> Self {
+        Self { stream, f }
+    }
+}
+
+impl<I, F, S> Iterator for LendWith<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I) -> S,
+{
+    type Item = (S, I::Item);
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await?;
+        Some(((self.f)(&self.stream), item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<I, F, S> FusedIterator for LendWith<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(&I) -> S,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::Iterator;
+
+    /// A paginator which tracks the cursor of the next page.
+    struct Pages {
+        cursor: usize,
+    }
+
+    impl Iterator for Pages {
+        type Item = &'static str;
+
+        async fn next(&mut self) -> Option<Self::Item> {
+            let page = ["a", "b", "c"].get(self.cursor).copied();
+            self.cursor += 1;
+            page
+        }
+    }
+
+    #[test]
+    fn collect_cursors() {
+        block_on(async {
+            let out: Vec<_> = Pages { cursor: 0 }
+                .lend_with(|pages| pages.cursor)
+                .collect()
+                .await;
+            assert_eq!(out, vec![(1, "a"), (2, "b"), (3, "c")]);
+        })
+    }
+}
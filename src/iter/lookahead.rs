@@ -0,0 +1,133 @@
+use crate::{FusedIterator, Iterator};
+use std::collections::VecDeque;
+
+/// An iterator with a `peek_nth()` that returns a reference to one of the
+/// next `n` elements.
+#[derive(Debug)]
+pub struct Lookahead<I: Iterator> {
+    stream: I,
+    /// Elements read ahead of time, in order. Never holds more than `n`.
+    buf: VecDeque<I::Item>,
+    n: usize,
+    done: bool,
+}
+
+impl<I: Iterator> Lookahead<I> {
+    pub(crate) fn new(stream: I, n: usize) -> Self {
+        assert!(n != 0, "lookahead size must be greater than zero");
+        Self {
+            stream,
+            buf: VecDeque::with_capacity(n),
+            n,
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the element `idx` positions ahead without
+    /// advancing the iterator, so `peek_nth(0)` is the next element.
+    ///
+    /// Returns `None` if the iterator ends before reaching that element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not less than the lookahead size `n`.
+    pub async fn peek_nth(&mut self, idx: usize) -> Option<&I::Item> {
+        assert!(
+            idx < self.n,
+            "lookahead index {} out of range for lookahead of {}",
+            idx,
+            self.n
+        );
+        while self.buf.len() <= idx && !self.done {
+            match self.stream.next().await {
+                Some(item) => self.buf.push_back(item),
+                None => self.done = true,
+            }
+        }
+        self.buf.get(idx)
+    }
+
+    /// Returns a reference to the next element without advancing the
+    /// iterator.
+    pub async fn peek(&mut self) -> Option<&I::Item> {
+        self.peek_nth(0).await
+    }
+}
+
+impl<I: Iterator> Iterator for Lookahead<I> {
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.buf.pop_front() {
+            return Some(item);
+        }
+        if self.done {
+            return None;
+        }
+        let item = self.stream.next().await;
+        self.done = item.is_none();
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buf.len();
+        if self.done {
+            return (buffered, Some(buffered));
+        }
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(buffered),
+            upper.and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
+
+impl<I: Iterator> FusedIterator for Lookahead<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn peek_and_yield_in_order() {
+        block_on(async {
+            let mut iter = from_iter(1..=4).lookahead(3);
+            assert_eq!(iter.peek_nth(2).await, Some(&3));
+            assert_eq!(iter.peek_nth(0).await, Some(&1));
+            assert_eq!(iter.next().await, Some(1));
+            assert_eq!(iter.peek_nth(2).await, Some(&4));
+            let rest: Vec<_> = iter.collect().await;
+            assert_eq!(rest, vec![2, 3, 4]);
+        })
+    }
+
+    #[test]
+    fn peek_past_end() {
+        block_on(async {
+            let mut iter = from_iter(1..=2).lookahead(3);
+            assert_eq!(iter.peek_nth(2).await, None);
+            assert_eq!(iter.peek_nth(1).await, Some(&2));
+            assert_eq!(iter.size_hint(), (2, Some(2)));
+            assert_eq!(iter.next().await, Some(1));
+            assert_eq!(iter.next().await, Some(2));
+            assert_eq!(iter.peek().await, None);
+            assert_eq!(iter.next().await, None);
+        })
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn peek_beyond_capacity() {
+        block_on(async {
+            let mut iter = from_iter(1..=4).lookahead(2);
+            iter.peek_nth(2).await;
+        })
+    }
+
+    #[test]
+    #[should_panic(expected = "greater than zero")]
+    fn zero_size() {
+        let _ = from_iter(1..=4).lookahead(0);
+    }
+}
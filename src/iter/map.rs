@@ -1,5 +1,4 @@
-use crate::Iterator;
-use core::future::Future;
+use crate::{ExactSizeIterator, Iterator};
 
 /// An iterator that maps value of another stream with a function.
 #[derive(Debug)]
@@ -14,11 +13,10 @@ impl<I, F> Map<I, F> {
     }
 }
 
-impl<I, F, B, Fut> Iterator for Map<I, F>
+impl<I, F, B> Iterator for Map<I, F>
 where
     I: Iterator,
-    F: FnMut(I::Item) -> Fut,
-    Fut: Future<Output = B>,
+    F: AsyncFnMut(I::Item) -> B,
 {
     type Item = B;
 
@@ -27,4 +25,70 @@ where
         let out = (self.f)(item).await;
         Some(out)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+
+    async fn count(self) -> usize {
+        // Mapping doesn't change the number of items, so there's no need to
+        // run the closure.
+        self.stream.count().await
+    }
+}
+
+impl<I, F, B> ExactSizeIterator for Map<I, F>
+where
+    I: ExactSizeIterator,
+    F: AsyncFnMut(I::Item) -> B,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn mapper_state_persists() {
+        block_on(async {
+            let mut seen = Vec::new();
+            let out: Vec<_> = from_iter(1..=3)
+                .map(async |x| {
+                    seen.push(x);
+                    x * 10 + seen.len()
+                })
+                .collect()
+                .await;
+            assert_eq!(out, vec![11, 22, 33]);
+            assert_eq!(seen, vec![1, 2, 3]);
+        })
+    }
+
+    #[test]
+    fn count_skips_closure() {
+        block_on(async {
+            let count = from_iter(0..4)
+                .map(|_: i32| -> core::future::Ready<i32> { panic!("mapper called") })
+                .count()
+                .await;
+            assert_eq!(count, 4);
+        })
+    }
+
+    #[test]
+    fn exact_size() {
+        use crate::ExactSizeIterator;
+
+        block_on(async {
+            let data = [1, 2, 3];
+            let mut iter = from_iter(data.iter())
+                .cloned()
+                .map(|x| async move { x * 2 });
+            assert_eq!(iter.len(), 3);
+            iter.next().await;
+            assert_eq!(iter.len(), 2);
+            assert!(!iter.is_empty());
+        })
+    }
 }
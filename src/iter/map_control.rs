@@ -0,0 +1,103 @@
+use crate::{FusedIterator, Iterator};
+use core::ops::ControlFlow;
+
+/// An iterator that maps the elements of another iterator until the mapper
+/// asks it to stop.
+#[derive(Debug)]
+pub struct MapControl<I, F> {
+    stream: I,
+    f: F,
+    done: bool,
+}
+
+impl<I, F> MapControl<I, F> {
+    pub(crate) fn new(stream: I, f: F) -> Self {
+        Self {
+            stream,
+            f,
+            done: false,
+        }
+    }
+}
+
+impl<I, F, B> Iterator for MapControl<I, F>
+where
+    I: Iterator,
+    F: AsyncFnMut(I::Item) -> ControlFlow<(), B>,
+{
+    type Item = B;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let Some(item) = self.stream.next().await else {
+            self.done = true;
+            return None;
+        };
+        match (self.f)(item).await {
+            ControlFlow::Continue(out) => Some(out),
+            ControlFlow::Break(()) => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
+
+impl<I, F, B> FusedIterator for MapControl<I, F>
+where
+    I: Iterator,
+    F: AsyncFnMut(I::Item) -> ControlFlow<(), B>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::{block_on, Resuming};
+    use crate::{from_iter, Iterator};
+    use core::cell::Cell;
+    use core::ops::ControlFlow;
+
+    #[test]
+    fn break_stops_iteration() {
+        block_on(async {
+            let polled = Cell::new(0);
+            let source = vec![1, 2, 0, 3]
+                .into_iter()
+                .inspect(|_| polled.set(polled.get() + 1));
+            let mut iter = from_iter(source).map_control(|x| async move {
+                if x == 0 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(x * 10)
+                }
+            });
+            assert_eq!(iter.next().await, Some(10));
+            assert_eq!(iter.next().await, Some(20));
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.next().await, None);
+            assert_eq!(polled.get(), 3);
+        })
+    }
+
+    #[test]
+    fn fused_on_source_end() {
+        block_on(async {
+            let mut source = Resuming::new(vec![Some(1), None, Some(2)]);
+            let mut iter = (&mut source).map_control(async |x| ControlFlow::<(), _>::Continue(x));
+            assert_eq!(iter.next().await, Some(1));
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.next().await, None);
+            assert_eq!(source.polls, 2);
+        })
+    }
+}
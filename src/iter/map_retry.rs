@@ -0,0 +1,88 @@
+use crate::Iterator;
+
+/// An iterator that maps the values of another iterator with a fallible
+/// function, retrying each item a bounded number of times.
+#[derive(Debug)]
+pub struct MapRetry<I, F> {
+    stream: I,
+    attempts: usize,
+    f: F,
+}
+
+impl<I, F> MapRetry<I, F> {
+    pub(crate) fn new(stream: I, attempts: usize, f: F) -> Self {
+        assert!(attempts != 0, "`attempts` must be greater than zero");
+        Self {
+            stream,
+            attempts,
+            f,
+        }
+    }
+}
+
+impl<I, F, B, E> Iterator for MapRetry<I, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: AsyncFnMut(I::Item) -> Result<B, E>,
+{
+    type Item = Result<B, E>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await?;
+        for _ in 1..self.attempts {
+            if let Ok(out) = (self.f)(item.clone()).await {
+                return Some(Ok(out));
+            }
+        }
+        Some((self.f)(item).await)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn retries_until_success() {
+        block_on(async {
+            let mut calls = 0;
+            let out: Vec<Result<u8, &str>> = from_iter(vec![7])
+                .map_retry(3, |x| {
+                    calls += 1;
+                    let attempt = calls;
+                    async move {
+                        match attempt {
+                            1 | 2 => Err("flaky"),
+                            _ => Ok(x),
+                        }
+                    }
+                })
+                .collect()
+                .await;
+            assert_eq!(out, vec![Ok(7)]);
+            assert_eq!(calls, 3);
+        })
+    }
+
+    #[test]
+    fn gives_up_after_attempts() {
+        block_on(async {
+            let mut calls = 0;
+            let out: Vec<Result<u8, &str>> = from_iter(vec![1, 2])
+                .map_retry(2, |_| {
+                    calls += 1;
+                    async { Err("down") }
+                })
+                .collect()
+                .await;
+            assert_eq!(out, vec![Err("down"), Err("down")]);
+            assert_eq!(calls, 4);
+        })
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{ExactSizeIterator, FusedIterator, Iterator};
+
+/// An iterator that maps the values of another iterator and shows every
+/// mapped value to a side-channel closure.
+#[derive(Debug)]
+pub struct MapTap<I, F, G> {
+    stream: I,
+    f: F,
+    tap: G,
+}
+
+impl<I, F, G> MapTap<I, F, G> {
+    pub(crate) fn new(stream: I, f: F, tap: G) -> Self {
+        Self { stream, f, tap }
+    }
+}
+
+impl<I, F, G, B> Iterator for MapTap<I, F, G>
+where
+    I: Iterator,
+    F: AsyncFnMut(I::Item) -> B,
+    G: FnMut(&B),
+{
+    type Item = B;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await?;
+        let out = (self.f)(item).await;
+        (self.tap)(&out);
+        Some(out)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<I, F, G, B> ExactSizeIterator for MapTap<I, F, G>
+where
+    I: ExactSizeIterator,
+    F: AsyncFnMut(I::Item) -> B,
+    G: FnMut(&B),
+{
+}
+
+impl<I, F, G, B> FusedIterator for MapTap<I, F, G>
+where
+    I: FusedIterator,
+    F: AsyncFnMut(I::Item) -> B,
+    G: FnMut(&B),
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn tap_sees_every_output() {
+        block_on(async {
+            let mut log = Vec::new();
+            let out: Vec<_> = from_iter(1..=3)
+                .map_tap(|x| async move { x * 10 }, |y: &i32| log.push(*y))
+                .collect()
+                .await;
+            assert_eq!(out, vec![10, 20, 30]);
+            assert_eq!(log, out);
+        })
+    }
+}
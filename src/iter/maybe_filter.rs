@@ -0,0 +1,62 @@
+use crate::Iterator;
+
+/// An iterator that optionally filters the elements of another iterator.
+#[derive(Debug)]
+pub struct MaybeFilter<I, P> {
+    stream: I,
+    predicate: Option<P>,
+}
+
+impl<I, P> MaybeFilter<I, P> {
+    pub(crate) fn new(stream: I, predicate: Option<P>) -> Self {
+        Self { stream, predicate }
+    }
+}
+
+impl<I, P> Iterator for MaybeFilter<I, P>
+where
+    I: Iterator,
+    P: AsyncFnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let Some(predicate) = self.predicate.as_mut() else {
+            return self.stream.next().await;
+        };
+        loop {
+            let item = self.stream.next().await?;
+            if predicate(&item).await {
+                return Some(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.predicate {
+            Some(_) => (0, self.stream.size_hint().1),
+            None => self.stream.size_hint(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    fn pipeline(only_even: bool) -> impl Iterator<Item = i32> {
+        let predicate = async |x: &i32| x % 2 == 0;
+        from_iter(0..5).maybe_filter(only_even.then_some(predicate))
+    }
+
+    #[test]
+    fn toggled() {
+        block_on(async {
+            let out: Vec<_> = pipeline(true).collect().await;
+            assert_eq!(out, vec![0, 2, 4]);
+            let out: Vec<_> = pipeline(false).collect().await;
+            assert_eq!(out, vec![0, 1, 2, 3, 4]);
+        })
+    }
+}
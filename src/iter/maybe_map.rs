@@ -0,0 +1,55 @@
+use crate::Iterator;
+
+/// An iterator that optionally maps the elements of another iterator.
+#[derive(Debug)]
+pub struct MaybeMap<I, F> {
+    stream: I,
+    f: Option<F>,
+}
+
+impl<I, F> MaybeMap<I, F> {
+    pub(crate) fn new(stream: I, f: Option<F>) -> Self {
+        Self { stream, f }
+    }
+}
+
+impl<I, F> Iterator for MaybeMap<I, F>
+where
+    I: Iterator,
+    F: AsyncFnMut(I::Item) -> I::Item,
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await?;
+        match self.f.as_mut() {
+            Some(f) => Some(f(item).await),
+            None => Some(item),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    fn pipeline(double: bool) -> impl Iterator<Item = i32> {
+        let f = |x| async move { x * 2 };
+        from_iter(1..4).maybe_map(double.then_some(f))
+    }
+
+    #[test]
+    fn toggled() {
+        block_on(async {
+            let out: Vec<_> = pipeline(true).collect().await;
+            assert_eq!(out, vec![2, 4, 6]);
+            let out: Vec<_> = pipeline(false).collect().await;
+            assert_eq!(out, vec![1, 2, 3]);
+        })
+    }
+}
@@ -0,0 +1,117 @@
+use crate::Iterator;
+use core::convert::TryFrom;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// An iterator that updates a shared [`Meter`] as it is driven.
+#[derive(Debug)]
+pub struct Metered<I> {
+    stream: I,
+    meter: Meter,
+}
+
+impl<I> Metered<I> {
+    pub(crate) fn new(stream: I) -> (Self, Meter) {
+        let meter = Meter {
+            inner: Arc::new(MeterInner {
+                origin: Instant::now(),
+                items: AtomicUsize::new(0),
+                completed: AtomicBool::new(false),
+                last_activity: AtomicU64::new(0),
+            }),
+        };
+        let stream = Self {
+            stream,
+            meter: meter.clone(),
+        };
+        (stream, meter)
+    }
+}
+
+impl<I: Iterator> Iterator for Metered<I> {
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await;
+        let inner = &self.meter.inner;
+        let nanos = u64::try_from(inner.origin.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        inner.last_activity.store(nanos, Ordering::Relaxed);
+        if item.is_some() {
+            inner.items.fetch_add(1, Ordering::Relaxed);
+        } else {
+            inner.completed.store(true, Ordering::Relaxed);
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+/// A handle for observing a [`Metered`] iterator, possibly from another
+/// task.
+#[derive(Debug, Clone)]
+pub struct Meter {
+    inner: Arc<MeterInner>,
+}
+
+#[derive(Debug)]
+struct MeterInner {
+    origin: Instant,
+    items: AtomicUsize,
+    completed: AtomicBool,
+    /// Nanoseconds since `origin`.
+    last_activity: AtomicU64,
+}
+
+impl Meter {
+    /// Returns the number of items yielded by the iterator so far.
+    pub fn items(&self) -> usize {
+        self.inner.items.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the iterator has returned `None`.
+    pub fn completed(&self) -> bool {
+        self.inner.completed.load(Ordering::Relaxed)
+    }
+
+    /// Returns when the iterator last finished a call to `next`.
+    ///
+    /// Before the first call completes this is the time the iterator was
+    /// wrapped.
+    pub fn last_activity(&self) -> Instant {
+        let nanos = self.inner.last_activity.load(Ordering::Relaxed);
+        self.inner.origin + Duration::from_nanos(nanos)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+    use std::thread;
+
+    #[test]
+    fn observed_from_another_thread() {
+        block_on(async {
+            let (mut iter, meter) = from_iter(0..3).metered();
+            let started = meter.last_activity();
+
+            iter.next().await;
+            iter.next().await;
+            let observer = meter.clone();
+            let (items, completed) =
+                thread::spawn(move || (observer.items(), observer.completed()))
+                    .join()
+                    .unwrap();
+            assert_eq!((items, completed), (2, false));
+            assert!(meter.last_activity() >= started);
+
+            while iter.next().await.is_some() {}
+            assert_eq!(meter.items(), 3);
+            assert!(meter.completed());
+        })
+    }
+}
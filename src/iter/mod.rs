@@ -1,15 +1,178 @@
+mod append;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod assert_items;
+mod batching;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod buffer_while;
+mod chain;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod chain_many;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod chunks;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod chunks_by_weight;
+mod cloned;
+mod coalesce;
+mod copied;
+mod cycle_n;
+#[cfg(feature = "timer")]
+mod deadline;
+mod delta;
+mod either;
+mod enumerate;
+mod enumerate_map_while;
+mod err_context;
+mod err_into;
+mod filter;
+mod filter_sync;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod flat_map_unordered;
+mod flatten_options;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod flatten_unordered;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod frame_lines;
+#[cfg(feature = "std")]
+mod instrumented;
+mod intersperse;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod kmerge;
 mod lend;
 mod lend_mut;
+mod lend_with;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod lookahead;
 mod map;
+mod map_control;
+mod map_retry;
+mod map_tap;
+mod maybe_filter;
+mod maybe_map;
+#[cfg(feature = "std")]
+mod metered;
+mod pad_to;
+mod peekable;
+mod prepend;
+mod progress;
+mod progress_every;
+mod result_shunt;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod split_when;
+mod stateful;
+mod stateful_filter;
+#[cfg(feature = "timer")]
+mod stop_after;
+#[cfg(feature = "std")]
+mod timed;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod try_chunks;
+mod try_flatten;
+mod try_skip_while;
+mod try_take_while;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod windows_map;
+mod with_position;
+mod zip;
 
+pub use append::Append;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use assert_items::AssertItems;
+pub use batching::Batching;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use buffer_while::BufferWhile;
+pub use chain::Chain;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use chain_many::{chain_many, ChainMany};
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use chunks::Chunks;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use chunks_by_weight::ChunksByWeight;
+pub use cloned::Cloned;
+pub use coalesce::Coalesce;
+pub use copied::Copied;
+pub use cycle_n::CycleN;
+pub use delta::Delta;
+pub use either::EitherIter;
+pub use enumerate::Enumerate;
+pub use enumerate_map_while::EnumerateMapWhile;
+pub use err_context::ErrContext;
+pub use err_into::ErrInto;
+pub use filter::Filter;
+pub use filter_sync::FilterSync;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use flat_map_unordered::FlatMapUnordered;
+pub use flatten_options::FlattenOptions;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use flatten_unordered::FlattenUnordered;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use frame_lines::FrameLines;
+#[cfg(feature = "std")]
+pub use instrumented::{Instrumented, Stats};
+pub use intersperse::Intersperse;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use kmerge::{kmerge, KMerge};
 pub use lend::Lend;
 pub use lend_mut::LendMut;
+pub use lend_with::LendWith;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use lookahead::Lookahead;
 pub use map::Map;
+pub use map_control::MapControl;
+pub use map_retry::MapRetry;
+pub use map_tap::MapTap;
+pub use maybe_filter::MaybeFilter;
+pub use maybe_map::MaybeMap;
+#[cfg(feature = "std")]
+pub use metered::{Meter, Metered};
+pub use pad_to::PadTo;
+pub use peekable::Peekable;
+pub use prepend::Prepend;
+pub use progress::Progress;
+pub use progress_every::ProgressEvery;
+pub(crate) use result_shunt::ResultShunt;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use split_when::SplitWhen;
+pub use stateful::Stateful;
+pub use stateful_filter::StatefulFilter;
+#[cfg(feature = "timer")]
+pub use stop_after::StopAfter;
+#[cfg(feature = "std")]
+pub use timed::Timed;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use try_chunks::{TryChunks, TryChunksError};
+pub use try_flatten::TryFlatten;
+pub use try_skip_while::TrySkipWhile;
+pub use try_take_while::TryTakeWhile;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use windows_map::WindowsMap;
+pub use with_position::{Position, WithPosition};
+pub use zip::{zip_all, zip_iters, ZipAll};
 
-use crate::FromIterator;
+use crate::extend::Extend;
+use crate::try_extend::TryExtend;
+use crate::{FromIterator, IntoIterator, Product, Sum, Try};
 
 /// An interface for dealing with iterators.
-
+///
+/// # Cancellation safety
+///
+/// Dropping a `next` future before it completes, for example when it loses
+/// a `select!`, must not lose an item that was already taken from an
+/// underlying source unless the implementation documents otherwise.
+///
+/// Adapters in this crate that read several items per call, such as
+/// [`Chunks`], [`ChunksByWeight`], [`SplitWhen`], [`Coalesce`] and
+/// [`Peekable`], keep those items in the iterator itself so a cancelled call
+/// loses nothing. Adapters that hand an item to a closure, such as [`Map`]
+/// and [`Filter`], can't give it back: dropping their `next` future while the
+/// closure's future is pending drops that item.
+///
+/// The following adapters are not cancellation safe:
+///
+/// - [`Batching`]: the items the closure has read so far live in the
+///   closure's future, and are dropped with it.
+/// - [`ZipAll`]: items already read from some of the zipped iterators are
+///   dropped if the call is cancelled while waiting on another.
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub trait Iterator {
     /// The type of the elements being iterated over.
@@ -28,11 +191,90 @@ pub trait Iterator {
     fn map<B, F>(self, f: F) -> Map<Self, F>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> B,
+        F: AsyncFnMut(Self::Item) -> B,
     {
         Map::new(self, f)
     }
 
+    /// Creates an iterator which maps elements with `f` if one is given, and
+    /// yields them unchanged otherwise.
+    ///
+    /// Both cases produce the same type, which makes it possible to toggle a
+    /// mapping stage at runtime without boxing the pipeline.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn maybe_map<F>(self, f: Option<F>) -> MaybeMap<Self, F>
+    where
+        Self: Sized,
+        F: AsyncFnMut(Self::Item) -> Self::Item,
+    {
+        MaybeMap::new(self, f)
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element
+    /// should be yielded.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: AsyncFnMut(&Self::Item) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+
+    /// Creates an iterator which uses a synchronous closure to determine if
+    /// an element should be yielded.
+    ///
+    /// This is the same as [`Iterator::filter`], but takes a plain closure
+    /// rather than an async one, so predicates which don't need to await
+    /// anything can be written as `|x| x > &1`.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn filter_sync<P>(self, predicate: P) -> FilterSync<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        FilterSync::new(self, predicate)
+    }
+
+    /// Creates an iterator which filters elements with `predicate` if one is
+    /// given, and yields every element otherwise.
+    ///
+    /// Both cases produce the same type, which makes it possible to toggle a
+    /// filtering stage at runtime without boxing the pipeline.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn maybe_filter<P>(self, predicate: Option<P>) -> MaybeFilter<Self, P>
+    where
+        Self: Sized,
+        P: AsyncFnMut(&Self::Item) -> bool,
+    {
+        MaybeFilter::new(self, predicate)
+    }
+
+    /// Folds every element into an accumulator by applying an operation,
+    /// returning the final result.
+    async fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: AsyncFnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        while let Some(item) = self.next().await {
+            acc = f(acc, item).await;
+        }
+        acc
+    }
+
+    /// Calls a closure on each element of an iterator.
+    async fn for_each<F>(mut self, mut f: F)
+    where
+        Self: Sized,
+        F: AsyncFnMut(Self::Item),
+    {
+        while let Some(item) = self.next().await {
+            f(item).await;
+        }
+    }
+
     /// Transforms an iterator into a collection.
     #[must_use = "if you really need to exhaust the iterator, consider `.for_each(drop)` instead"]
     async fn collect<B: FromIterator<Self::Item>>(self) -> B
@@ -43,6 +285,17 @@ pub trait Iterator {
         fut.await
     }
 
+    /// Transforms an iterator of results into a collection, short-circuiting
+    /// on the first error.
+    #[must_use = "if you really need to exhaust the iterator, consider `.for_each(drop)` instead"]
+    async fn try_collect<T, E, B>(self) -> Result<B, E>
+    where
+        Self: Iterator<Item = Result<T, E>> + Sized,
+        B: FromIterator<T>,
+    {
+        self.collect().await
+    }
+
     /// Creates an iterator which yields a reference to `self` as well as
     /// the next value.
     #[must_use = "iterators do nothing unless iterated over"]
@@ -62,4 +315,1460 @@ pub trait Iterator {
     {
         LendMut::new(self)
     }
+
+    /// Creates an iterator which yields a snapshot of `self`, as produced by
+    /// `f`, as well as the next value.
+    ///
+    /// Unlike [`Iterator::lend`] this is a regular iterator, so the snapshots
+    /// can be stored or sent elsewhere.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn lend_with<F, S>(self, f: F) -> LendWith<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self) -> S,
+    {
+        LendWith::new(self, f)
+    }
+
+    /// Creates an iterator which flattens the `Ok` items of an iterator of
+    /// results, passing errors through as they are encountered.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn try_flatten<T, E>(self) -> TryFlatten<Self, T>
+    where
+        Self: Iterator<Item = Result<T, E>> + Sized,
+        T: IntoIterator,
+    {
+        TryFlatten::new(self)
+    }
+
+    /// Consumes the iterator, returning its last `n` items in order.
+    ///
+    /// Only the final `n` items are kept in memory while the iterator is
+    /// drained. If `n` is zero the iterator is not advanced at all.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    async fn last_n(mut self, n: usize) -> std::collections::VecDeque<Self::Item>
+    where
+        Self: Sized,
+    {
+        let mut buf = std::collections::VecDeque::new();
+        if n == 0 {
+            return buf;
+        }
+        while let Some(item) = self.next().await {
+            if buf.len() == n {
+                buf.pop_front();
+            } else if buf.len() == buf.capacity() {
+                buf.reserve_exact(buf.len().max(4).min(n - buf.len()));
+            }
+            buf.push_back(item);
+        }
+        buf
+    }
+
+    /// Eagerly advances the iterator by `n` items, returning it afterwards.
+    ///
+    /// Unlike a lazy `skip`, the items are dropped before this method returns.
+    async fn dropping(mut self, n: usize) -> Self
+    where
+        Self: Sized,
+    {
+        for _ in 0..n {
+            if self.next().await.is_none() {
+                break;
+            }
+        }
+        self
+    }
+
+    /// Creates an iterator which calls `f` with the running item count after
+    /// every `every` items, and once more with the final count when the
+    /// iterator is exhausted. The final count is not reported twice: if it is
+    /// a multiple of `every`, the regular report is the last call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `every` is zero.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn progress<F>(self, every: usize, f: F) -> Progress<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(usize),
+    {
+        Progress::new(self, every, f)
+    }
+
+    /// Folds every element into an accumulator with a fallible operation,
+    /// stopping at the first failure.
+    ///
+    /// The closure returns a [`Try`] value such as `Result` or `Option`.
+    /// When it short-circuits, that value is returned and no further items
+    /// are pulled from the iterator.
+    async fn try_fold<B, R, F>(&mut self, init: B, mut f: F) -> R
+    where
+        R: Try<Output = B>,
+        F: AsyncFnMut(B, Self::Item) -> R,
+    {
+        let mut acc = init;
+        while let Some(item) = self.next().await {
+            match f(acc, item).await.branch() {
+                core::ops::ControlFlow::Continue(next) => acc = next,
+                core::ops::ControlFlow::Break(residual) => return residual,
+            }
+        }
+        R::from_output(acc)
+    }
+
+    /// Folds the `Ok` values of an iterator of results into an accumulator,
+    /// stopping at the first error.
+    ///
+    /// If an error is encountered the accumulator is discarded and the error
+    /// is returned. No further items are pulled from the iterator after that.
+    async fn fold_ok<T, E, B, F>(&mut self, init: B, f: F) -> Result<B, E>
+    where
+        Self: Iterator<Item = Result<T, E>>,
+        F: AsyncFnMut(B, T) -> B,
+    {
+        let mut error = None;
+        let acc = ResultShunt::new(self, &mut error).fold(init, f).await;
+        error.map_or(Ok(acc), Err)
+    }
+
+    /// Sums the elements of an iterator.
+    async fn sum<S>(self) -> S
+    where
+        Self: Sized,
+        S: Sum<Self::Item>,
+    {
+        S::sum(self).await
+    }
+
+    /// Iterates over the entire iterator, multiplying all the elements.
+    async fn product<P>(self) -> P
+    where
+        Self: Sized,
+        P: Product<Self::Item>,
+    {
+        P::product(self).await
+    }
+
+    /// Takes two iterators and creates a new iterator over both in sequence.
+    ///
+    /// Once the first iterator returns `None` it is dropped and never polled
+    /// again.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn chain<U>(self, other: U) -> Chain<Self, U>
+    where
+        Self: Sized,
+        U: Iterator<Item = Self::Item>,
+    {
+        Chain::new(self, other)
+    }
+
+    /// Borrows an iterator, rather than consuming it.
+    ///
+    /// This is useful to allow applying iterator adapters while still
+    /// retaining ownership of the original iterator.
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    /// Creates an iterator which calls a closure with mutable access to the
+    /// underlying iterator for every item it yields.
+    ///
+    /// The closure may consume any number of items to produce a single value,
+    /// and returns `None` to end the iteration.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn batching<B, F>(self, f: F) -> Batching<Self, F>
+    where
+        Self: Sized,
+        F: AsyncFnMut(&mut Self) -> Option<B>,
+    {
+        Batching::new(self, f)
+    }
+
+    /// Consumes the iterator, counting the number of iterations and returning
+    /// it.
+    async fn count(mut self) -> usize
+    where
+        Self: Sized,
+    {
+        let mut count = 0;
+        while self.next().await.is_some() {
+            count += 1;
+        }
+        count
+    }
+
+    /// Creates an iterator which splits the items into segments, ending each
+    /// segment at an item matching the predicate.
+    ///
+    /// The delimiting items are dropped unless
+    /// [`SplitWhen::keep_delimiter`] is set. Consecutive delimiters produce
+    /// empty segments, and any items after the final delimiter are yielded as
+    /// a last segment.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn split_when<P>(self, predicate: P) -> SplitWhen<Self, P>
+    where
+        Self: Sized,
+        P: AsyncFnMut(&Self::Item) -> bool,
+    {
+        SplitWhen::new(self, predicate)
+    }
+
+    /// Creates an iterator which yields the items in `Vec`s of `size` items
+    /// each.
+    ///
+    /// The final chunk may be shorter than `size` if the iterator doesn't
+    /// divide evenly. After that chunk the iterator returns `None` without
+    /// polling the underlying iterator again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn chunks(self, size: usize) -> Chunks<Self>
+    where
+        Self: Sized,
+    {
+        Chunks::new(self, size)
+    }
+
+    /// Creates an iterator which ends once `budget` has elapsed since the
+    /// first call to `next`, regardless of whether items are ready.
+    ///
+    /// If the deadline passes while waiting for an item, the underlying
+    /// `next` future is dropped and the item it would have produced is
+    /// abandoned. Once the budget is exhausted the iterator yields `None`
+    /// forever.
+    #[cfg(feature = "timer")]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn stop_after(self, budget: core::time::Duration) -> StopAfter<Self>
+    where
+        Self: Sized,
+    {
+        StopAfter::new(self, budget)
+    }
+
+    /// Creates an iterator which flattens an iterator of iterators, driving
+    /// up to `limit` inner iterators concurrently.
+    ///
+    /// Items are yielded in the order they become ready, rather than in the
+    /// order of the inner iterators. Ready inner iterators take turns, so one
+    /// that is always ready can't starve the others.
+    ///
+    /// Each inner iterator is driven by a boxed future, which costs one
+    /// allocation per inner iterator rather than one per item. As a result
+    /// inner iterators must be `'static`, so they can't borrow from their
+    /// surroundings, and the returned iterator is not `Send`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn flatten_unordered(self, limit: usize) -> FlattenUnordered<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator + 'static,
+    {
+        FlattenUnordered::new(self, limit)
+    }
+
+    /// Consumes the iterator, returning its `k` largest items sorted in
+    /// descending order.
+    ///
+    /// Only `k` items are kept in memory while the iterator is drained.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    async fn top_k(mut self, k: usize) -> std::vec::Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        use core::cmp::Reverse;
+        use std::collections::BinaryHeap;
+
+        if k == 0 {
+            return std::vec::Vec::new();
+        }
+        // A min-heap of the largest items seen so far.
+        let mut heap = BinaryHeap::with_capacity(k.min(self.size_hint().0));
+        while let Some(item) = self.next().await {
+            if heap.len() < k {
+                heap.push(Reverse(item));
+            } else if let Some(mut min) = heap.peek_mut() {
+                if item > min.0 {
+                    *min = Reverse(item);
+                }
+            }
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(item)| item)
+            .collect()
+    }
+
+    /// Creates an iterator which clones all of its elements.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn cloned<'a, T>(self) -> Cloned<Self>
+    where
+        Self: Iterator<Item = &'a T> + Sized,
+        T: Clone + 'a,
+    {
+        Cloned::new(self)
+    }
+
+    /// Creates an iterator which copies all of its elements.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn copied<'a, T>(self) -> Copied<Self>
+    where
+        Self: Iterator<Item = &'a T> + Sized,
+        T: Copy + 'a,
+    {
+        Copied::new(self)
+    }
+
+    /// Creates an iterator which records how many items it yields, whether it
+    /// was exhausted, and whether it has been dropped.
+    ///
+    /// The statistics can be read through the handle returned by
+    /// [`Instrumented::stats`], including after the iterator was dropped.
+    #[cfg(feature = "std")]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn instrumented(self) -> Instrumented<Self>
+    where
+        Self: Sized,
+    {
+        Instrumented::new(self)
+    }
+
+    /// Creates an iterator which can use [`Peekable::peek`] to look at the
+    /// next element of the iterator without consuming it.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn peekable(self) -> Peekable<Self>
+    where
+        Self: Sized,
+    {
+        Peekable::new(self)
+    }
+
+    /// Creates an iterator which places a copy of `separator` between
+    /// adjacent items of the original iterator.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn intersperse(self, separator: Self::Item) -> Intersperse<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Intersperse::new(self, separator)
+    }
+
+    /// Creates an iterator which re-frames chunks of bytes into
+    /// newline-delimited records, such as lines of NDJSON.
+    ///
+    /// Records may span multiple chunks. The newline itself is not included
+    /// in the records, and any bytes following the final newline are yielded
+    /// as a last record.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn frame_lines(self) -> FrameLines<Self>
+    where
+        Self: Sized,
+        Self::Item: AsRef<[u8]>,
+    {
+        FrameLines::new(self)
+    }
+
+    /// Creates an iterator which yields the values of an iterator of
+    /// options, skipping over the `None`s.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn flatten_options<T>(self) -> FlattenOptions<Self>
+    where
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        FlattenOptions::new(self)
+    }
+
+    /// Creates an iterator which converts the errors of an iterator of
+    /// results into another error type using [`From`].
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn err_into<T, E, E2>(self) -> ErrInto<Self, E2>
+    where
+        Self: Iterator<Item = Result<T, E>> + Sized,
+        E2: From<E>,
+    {
+        ErrInto::new(self)
+    }
+
+    /// Creates an iterator which pairs every error of an iterator of results
+    /// with a clone of `ctx`.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn context<T, E, C>(self, ctx: C) -> ErrContext<Self, C>
+    where
+        Self: Iterator<Item = Result<T, E>> + Sized,
+        C: Clone,
+    {
+        ErrContext::new(self, ctx)
+    }
+
+    /// Creates an iterator which maps each item with a fallible async
+    /// function, calling it up to `attempts` times per item before yielding
+    /// the last error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attempts` is zero.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn map_retry<B, E, F>(self, attempts: usize, f: F) -> MapRetry<Self, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: AsyncFnMut(Self::Item) -> Result<B, E>,
+    {
+        MapRetry::new(self, attempts, f)
+    }
+
+    /// Creates an iterator which yields each item together with the time the
+    /// call to `next` spent waiting for it.
+    #[cfg(feature = "std")]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn timed(self) -> Timed<Self>
+    where
+        Self: Sized,
+    {
+        Timed::new(self)
+    }
+
+    /// Consumes the iterator, collecting exactly `N` items into an array.
+    ///
+    /// If the iterator yields fewer than `N` items, the items that were
+    /// yielded are returned as the error. If it yields more, the first
+    /// `N + 1` items are returned as the error and the rest are left in the
+    /// iterator.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    async fn collect_array<const N: usize>(
+        mut self,
+    ) -> Result<[Self::Item; N], std::vec::Vec<Self::Item>>
+    where
+        Self: Sized,
+    {
+        let mut buf = std::vec::Vec::with_capacity(N);
+        while buf.len() < N {
+            match self.next().await {
+                Some(item) => buf.push(item),
+                None => return Err(buf),
+            }
+        }
+        if let Some(item) = self.next().await {
+            buf.push(item);
+            return Err(buf);
+        }
+        core::convert::TryFrom::try_from(buf)
+    }
+
+    /// Creates an iterator which calls `f` with the running item count after
+    /// every `n` items.
+    ///
+    /// Unlike [`progress`](Iterator::progress), no call is made when the
+    /// iterator is exhausted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn progress_every<F>(self, n: usize, f: F) -> ProgressEvery<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(usize),
+    {
+        ProgressEvery::new(self, n, f)
+    }
+
+    /// Writes every element of the iterator with a fallible closure,
+    /// returning the number of elements written.
+    ///
+    /// Iteration stops at the first error, which is returned together with
+    /// the number of elements that were written successfully before it.
+    async fn drain_into<F, E>(mut self, mut f: F) -> Result<usize, (usize, E)>
+    where
+        Self: Sized,
+        F: AsyncFnMut(Self::Item) -> Result<(), E>,
+    {
+        let mut written = 0;
+        while let Some(item) = self.next().await {
+            if let Err(err) = f(item).await {
+                return Err((written, err));
+            }
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    /// Creates an iterator which keeps a shared [`Meter`] up to date as it
+    /// is driven.
+    ///
+    /// The meter can be cloned and read from other tasks, for example to
+    /// detect a stalled consumer.
+    #[cfg(feature = "std")]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn metered(self) -> (Metered<Self>, Meter)
+    where
+        Self: Sized,
+    {
+        Metered::new(self)
+    }
+
+    /// Creates an iterator which yields the difference between each element
+    /// and the one before it.
+    ///
+    /// The returned iterator yields one element fewer than `self`.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn delta(self) -> Delta<Self>
+    where
+        Self: Sized,
+        Self::Item: core::ops::Sub + Copy,
+    {
+        Delta::new(self)
+    }
+
+    /// Creates an iterator which calls `f` on every overlapping window of
+    /// `size` consecutive items.
+    ///
+    /// Windows advance one item at a time, so a source of `n` items yields
+    /// `n - size + 1` outputs, or none if it has fewer than `size` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn windows_map<B, F>(self, size: usize, f: F) -> WindowsMap<Self, F>
+    where
+        Self: Sized,
+        F: AsyncFnMut(&[Self::Item]) -> B,
+    {
+        WindowsMap::new(self, size, f)
+    }
+
+    /// Collects an iterator of key-value pairs into a `HashMap`, combining
+    /// the values of duplicate keys with `merge`.
+    ///
+    /// `merge` is called with the value already in the map, which it updates
+    /// in place, and the new value, in iteration order. This matches
+    /// [`MergeExtend::extend_with`](crate::prelude::MergeExtend::extend_with).
+    #[cfg(feature = "std")]
+    async fn collect_map_with<K, V, F>(mut self, mut merge: F) -> std::collections::HashMap<K, V>
+    where
+        Self: Iterator<Item = (K, V)> + Sized,
+        K: Eq + core::hash::Hash,
+        F: AsyncFnMut(&mut V, V),
+    {
+        use std::collections::hash_map::Entry;
+
+        let capacity = crate::extend::hash_reserve_hint(self.size_hint());
+        let mut map = std::collections::HashMap::with_capacity(capacity);
+        while let Some((key, value)) = self.next().await {
+            match map.entry(key) {
+                Entry::Occupied(mut entry) => merge(entry.get_mut(), value).await,
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+            }
+        }
+        map
+    }
+
+    /// Extends `collection` with the items of the iterator, stopping at the
+    /// first item it can't store.
+    ///
+    /// Items already in `collection`, and items stored before an error, are
+    /// kept.
+    async fn try_collect_into<C>(self, collection: &mut C) -> Result<&mut C, C::Error>
+    where
+        Self: Sized,
+        C: TryExtend<Self::Item>,
+    {
+        collection.try_extend(self).await?;
+        Ok(collection)
+    }
+
+    /// Creates an iterator which threads `init` through `f` alongside each
+    /// item, like `scan`, but keeps the state reachable through
+    /// [`Stateful::state`] and [`Stateful::into_state`].
+    ///
+    /// Iteration ends when `f` returns `None`. Since `collect` and other
+    /// consuming methods take the iterator by value, drain it through
+    /// [`by_ref`] to keep access to the state afterwards.
+    ///
+    /// [`by_ref`]: Iterator::by_ref
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn stateful<St, B, F>(self, init: St, f: F) -> Stateful<Self, St, F>
+    where
+        Self: Sized,
+        F: AsyncFnMut(&mut St, Self::Item) -> Option<B>,
+    {
+        Stateful::new(self, init, f)
+    }
+
+    /// Creates an iterator which merges adjacent items with `f`.
+    ///
+    /// `f` is called with the item built up so far and the next item. If it
+    /// returns `Ok`, the merged item replaces both; if it returns `Err`, the
+    /// first item is yielded and the second starts a new run.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn coalesce<F>(self, f: F) -> Coalesce<Self, F>
+    where
+        Self: Sized,
+        F: AsyncFnMut(Self::Item, Self::Item) -> Result<Self::Item, (Self::Item, Self::Item)>,
+    {
+        Coalesce::new(self, f)
+    }
+
+    /// Creates an iterator which yields the items in `Vec`s whose total
+    /// weight, as measured by `weigh`, doesn't exceed `max_weight`.
+    ///
+    /// An item heavier than `max_weight` on its own is yielded as a batch of
+    /// one.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn chunks_by_weight<F>(self, max_weight: usize, weigh: F) -> ChunksByWeight<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> usize,
+    {
+        ChunksByWeight::new(self, max_weight, weigh)
+    }
+
+    /// Sums the elements of an iterator as `f64`s using Kahan's compensated
+    /// summation.
+    ///
+    /// This tracks the rounding error lost by each addition, so the result
+    /// stays accurate over long streams where a naive sum would drift.
+    async fn sum_kahan(mut self) -> f64
+    where
+        Self: Sized,
+        Self::Item: Into<f64>,
+    {
+        let mut sum = 0.0;
+        let mut compensation = 0.0;
+        while let Some(item) = self.next().await {
+            let y = item.into() - compensation;
+            let t = sum + y;
+            compensation = (t - sum) - y;
+            sum = t;
+        }
+        sum
+    }
+
+    /// Advances the iterator, giving up if no item is ready within `dur`.
+    ///
+    /// Returns `None` if `dur` elapsed first, and `Some` with the result of
+    /// `next` otherwise. On timeout the `next` future is dropped, so this is
+    /// exactly as cancellation safe as `self`; for the adapters documented as
+    /// cancellation safe no item is lost.
+    #[cfg(feature = "timer")]
+    async fn next_timeout(&mut self, dur: core::time::Duration) -> Option<Option<Self::Item>> {
+        deadline::race_deadline(self.next(), futures_timer::Delay::new(dur)).await
+    }
+
+    /// Creates an iterator which maps each item to an inner iterator with
+    /// `f`, driving up to `limit` inner iterators concurrently.
+    ///
+    /// Items are yielded in the order they become ready. The iterator ends
+    /// once `self` and every inner iterator are exhausted. As with
+    /// [`flatten_unordered`](Iterator::flatten_unordered), inner iterators
+    /// must be `'static` and the returned iterator is not `Send`.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn flat_map_unordered<U, F>(
+        self,
+        limit: core::num::NonZeroUsize,
+        f: F,
+    ) -> FlatMapUnordered<Self, U, F>
+    where
+        Self: Sized,
+        U: IntoIterator + 'static,
+        F: FnMut(Self::Item) -> U,
+    {
+        FlatMapUnordered::new(self, limit, f)
+    }
+
+    /// Creates an iterator which uses a closure with access to mutable state
+    /// to determine if an element should be yielded.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn stateful_filter<St, F>(self, init: St, f: F) -> StatefulFilter<Self, St, F>
+    where
+        Self: Sized,
+        F: AsyncFnMut(&mut St, &Self::Item) -> bool,
+    {
+        StatefulFilter::new(self, init, f)
+    }
+
+    /// Collects an iterator of key-value pairs into a `HashMap`, keeping the
+    /// first value seen for each key.
+    #[cfg(feature = "std")]
+    async fn collect_map_first<K, V>(mut self) -> std::collections::HashMap<K, V>
+    where
+        Self: Iterator<Item = (K, V)> + Sized,
+        K: Eq + core::hash::Hash,
+    {
+        let capacity = crate::extend::hash_reserve_hint(self.size_hint());
+        let mut map = std::collections::HashMap::with_capacity(capacity);
+        while let Some((key, value)) = self.next().await {
+            map.entry(key).or_insert(value);
+        }
+        map
+    }
+
+    /// Splits an iterator of pairs into two existing collections.
+    ///
+    /// Items already in `a` and `b` are kept. Pairs are buffered on the stack
+    /// and handed to `a` and `b` in batches of up to 32, so each collection is
+    /// extended once per batch rather than once per item.
+    async fn unzip_into<X, Y, A, B>(mut self, a: &mut A, b: &mut B)
+    where
+        Self: Iterator<Item = (X, Y)> + Sized,
+        A: Extend<X>,
+        B: Extend<Y>,
+    {
+        const BATCH: usize = 32;
+        loop {
+            let mut xs: [Option<X>; BATCH] = core::array::from_fn(|_| None);
+            let mut ys: [Option<Y>; BATCH] = core::array::from_fn(|_| None);
+            let mut len = 0;
+            while len < BATCH {
+                let Some((x, y)) = self.next().await else {
+                    break;
+                };
+                xs[len] = Some(x);
+                ys[len] = Some(y);
+                len += 1;
+            }
+            if len == 0 {
+                return;
+            }
+            a.extend(crate::from_iter(
+                core::iter::IntoIterator::into_iter(xs).flatten(),
+            ))
+            .await;
+            b.extend(crate::from_iter(
+                core::iter::IntoIterator::into_iter(ys).flatten(),
+            ))
+            .await;
+            if len < BATCH {
+                return;
+            }
+        }
+    }
+
+    /// Creates an iterator which gives the current iteration count as well as
+    /// the next value.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn enumerate(self) -> Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        Enumerate::new(self, 0)
+    }
+
+    /// Creates an iterator like [`enumerate`](Iterator::enumerate), but whose
+    /// first yielded index is `start`.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn enumerate_from(self, start: usize) -> Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        Enumerate::new(self, start)
+    }
+
+    /// Consumes the iterator, grouping the values produced by `f` by their
+    /// keys.
+    ///
+    /// Values within each group keep the order in which they were
+    /// encountered.
+    #[cfg(feature = "std")]
+    async fn group_map<K, V, F>(
+        mut self,
+        mut f: F,
+    ) -> std::collections::HashMap<K, std::vec::Vec<V>>
+    where
+        Self: Sized,
+        K: Eq + core::hash::Hash,
+        F: FnMut(Self::Item) -> (K, V),
+    {
+        let capacity = crate::extend::hash_reserve_hint(self.size_hint());
+        let mut groups = std::collections::HashMap::with_capacity(capacity);
+        while let Some(item) = self.next().await {
+            let (key, value) = f(item);
+            groups
+                .entry(key)
+                .or_insert_with(std::vec::Vec::new)
+                .push(value);
+        }
+        groups
+    }
+
+    /// Consumes the iterator, grouping its items by the key returned by `f`.
+    ///
+    /// Items within each group keep the order in which they were
+    /// encountered.
+    #[cfg(feature = "std")]
+    async fn group_map_by<K, F>(
+        self,
+        mut f: F,
+    ) -> std::collections::HashMap<K, std::vec::Vec<Self::Item>>
+    where
+        Self: Sized,
+        K: Eq + core::hash::Hash,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.group_map(|item| (f(&item), item)).await
+    }
+
+    /// Consumes an iterator of byte chunks, concatenating them into a single
+    /// `Vec<u8>`.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    async fn concat_bytes(mut self) -> std::vec::Vec<u8>
+    where
+        Self: Sized,
+        Self::Item: AsRef<[u8]>,
+    {
+        let mut out = std::vec::Vec::new();
+        while let Some(chunk) = self.next().await {
+            out.extend_from_slice(chunk.as_ref());
+        }
+        out
+    }
+
+    /// Routes each element into one of two existing collections, depending
+    /// on `predicate`.
+    ///
+    /// Elements for which `predicate` returns `true` extend `left`, the rest
+    /// extend `right`. Items already in either collection are kept. Elements
+    /// are buffered on the stack and handed over in batches: both collections
+    /// are extended whenever one side has 32 pending elements, and once more
+    /// when the iterator ends.
+    async fn demux<P, A, B>(mut self, mut predicate: P, left: &mut A, right: &mut B)
+    where
+        Self: Sized,
+        P: AsyncFnMut(&Self::Item) -> bool,
+        A: Extend<Self::Item>,
+        B: Extend<Self::Item>,
+    {
+        const BATCH: usize = 32;
+        loop {
+            let mut ls: [Option<Self::Item>; BATCH] = core::array::from_fn(|_| None);
+            let mut rs: [Option<Self::Item>; BATCH] = core::array::from_fn(|_| None);
+            let (mut l, mut r) = (0, 0);
+            let mut done = false;
+            while l < BATCH && r < BATCH {
+                let Some(item) = self.next().await else {
+                    done = true;
+                    break;
+                };
+                if predicate(&item).await {
+                    ls[l] = Some(item);
+                    l += 1;
+                } else {
+                    rs[r] = Some(item);
+                    r += 1;
+                }
+            }
+            if l > 0 {
+                left.extend(crate::from_iter(
+                    core::iter::IntoIterator::into_iter(ls).flatten(),
+                ))
+                .await;
+            }
+            if r > 0 {
+                right
+                    .extend(crate::from_iter(
+                        core::iter::IntoIterator::into_iter(rs).flatten(),
+                    ))
+                    .await;
+            }
+            if done {
+                return;
+            }
+        }
+    }
+
+    /// Consumes the iterator, collecting its items into `Vec`s of `size`
+    /// items each.
+    ///
+    /// The final chunk is shorter than `size` if the iterator doesn't divide
+    /// evenly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    async fn collect_chunks(self, size: usize) -> std::vec::Vec<std::vec::Vec<Self::Item>>
+    where
+        Self: Sized,
+    {
+        self.chunks(size).collect().await
+    }
+
+    /// Creates an iterator which replays this iterator `n` times.
+    ///
+    /// Each round is driven from a clone of the original iterator, except
+    /// the last, which uses the original itself. If `n` is zero nothing is
+    /// cloned or polled, and if a round yields nothing the iterator ends.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn cycle_n(self, n: usize) -> CycleN<Self>
+    where
+        Self: Sized + Clone,
+    {
+        CycleN::new(self, n)
+    }
+
+    /// Maps each element with `f` and passes a reference to every mapped
+    /// value to `tap` before yielding it.
+    ///
+    /// This is useful for logging or recording the output of a transformation
+    /// without threading that work through the mapping closure itself.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn map_tap<B, F, G>(self, f: F, tap: G) -> MapTap<Self, F, G>
+    where
+        Self: Sized,
+        F: AsyncFnMut(Self::Item) -> B,
+        G: FnMut(&B),
+    {
+        MapTap::new(self, f, tap)
+    }
+
+    /// Creates an iterator over results which skips `Ok` elements while
+    /// `predicate` returns `true`.
+    ///
+    /// Errors are yielded as they are encountered, both while skipping and
+    /// afterwards, and don't affect when skipping ends. This matches
+    /// `TryStreamExt::try_skip_while` from the `futures` crate.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn try_skip_while<T, E, P>(self, predicate: P) -> TrySkipWhile<Self, P>
+    where
+        Self: Iterator<Item = Result<T, E>> + Sized,
+        P: AsyncFnMut(&T) -> bool,
+    {
+        TrySkipWhile::new(self, predicate)
+    }
+
+    /// Creates an iterator over results which yields `Ok` elements while
+    /// `predicate` returns `true`.
+    ///
+    /// Errors encountered before the predicate first returns `false` are
+    /// yielded without ending the iterator. Once an `Ok` element fails the
+    /// predicate, that element is dropped and the iterator ends, so later
+    /// errors are never observed. This matches `TryStreamExt::try_take_while`
+    /// from the `futures` crate.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn try_take_while<T, E, P>(self, predicate: P) -> TryTakeWhile<Self, P>
+    where
+        Self: Iterator<Item = Result<T, E>> + Sized,
+        P: AsyncFnMut(&T) -> bool,
+    {
+        TryTakeWhile::new(self, predicate)
+    }
+
+    /// Creates an iterator which checks an invariant on every element as it
+    /// passes through.
+    ///
+    /// When `check` returns an error in a debug build, the iterator panics
+    /// with the index of the offending element and the returned message. In
+    /// release builds the check is compiled out, unless the `log-assertions`
+    /// feature is enabled, in which case failures are printed to stderr and
+    /// iteration continues.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn assert_items<F>(self, check: F) -> AssertItems<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Result<(), std::string::String>,
+    {
+        AssertItems::new(self, check)
+    }
+
+    /// Maps each element with `f` until it returns `ControlFlow::Break`.
+    ///
+    /// Elements mapped to `Continue` are yielded. The first `Break` ends the
+    /// iterator without polling the underlying iterator again.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn map_control<B, F>(self, f: F) -> MapControl<Self, F>
+    where
+        Self: Sized,
+        F: AsyncFnMut(Self::Item) -> core::ops::ControlFlow<(), B>,
+    {
+        MapControl::new(self, f)
+    }
+
+    /// Creates an iterator which yields the items in `Vec`s, adding each item
+    /// to the current batch for as long as `predicate` accepts it.
+    ///
+    /// The predicate sees the batch so far and the candidate item. When it
+    /// returns `false` the batch is yielded and the candidate starts the next
+    /// one. The first item of a batch is always accepted, so the predicate is
+    /// never called with an empty batch.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn buffer_while<P>(self, predicate: P) -> BufferWhile<Self, P>
+    where
+        Self: Sized,
+        P: AsyncFnMut(&[Self::Item], &Self::Item) -> bool,
+    {
+        BufferWhile::new(self, predicate)
+    }
+
+    /// Creates an iterator which pairs each element with its [`Position`]:
+    /// first, middle, last, or only.
+    ///
+    /// To tell whether an element is the last one, the iterator reads one
+    /// element ahead of the one it yields.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn with_position(self) -> WithPosition<Self>
+    where
+        Self: Sized,
+    {
+        WithPosition::new(self)
+    }
+
+    /// Checks whether all elements of the iterator are equal.
+    ///
+    /// An empty iterator is all-equal. Iteration stops at the first element
+    /// that differs from its predecessor.
+    async fn all_equal(self) -> bool
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        self.all_equal_value().await.is_ok()
+    }
+
+    /// Returns the value shared by all elements of the iterator.
+    ///
+    /// Returns `Ok(None)` for an empty iterator, and `Err` with the first pair
+    /// of adjacent elements that differ otherwise. Iteration stops at the
+    /// first such pair.
+    async fn all_equal_value(mut self) -> Result<Option<Self::Item>, (Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        let first = match self.next().await {
+            Some(first) => first,
+            None => return Ok(None),
+        };
+        while let Some(item) = self.next().await {
+            if item != first {
+                return Err((first, item));
+            }
+        }
+        Ok(Some(first))
+    }
+
+    /// Creates an iterator which can look up to `n` elements ahead with
+    /// [`Lookahead::peek_nth`].
+    ///
+    /// Peeked elements are buffered and yielded in order before the
+    /// underlying iterator is polled again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn lookahead(self, n: usize) -> Lookahead<Self>
+    where
+        Self: Sized,
+    {
+        Lookahead::new(self, n)
+    }
+
+    /// Creates an iterator which yields the elements of this iterator and
+    /// then clones of `fill` until at least `len` elements have been yielded.
+    ///
+    /// Iterators that already yield `len` or more elements are unchanged.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn pad_to(self, len: usize, fill: Self::Item) -> PadTo<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        PadTo::new(self, len, fill)
+    }
+
+    /// Creates an iterator which yields `item` before the elements of this
+    /// iterator.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn prepend(self, item: Self::Item) -> Prepend<Self>
+    where
+        Self: Sized,
+    {
+        Prepend::new(self, item)
+    }
+
+    /// Creates an iterator which yields `item` after the elements of this
+    /// iterator.
+    ///
+    /// `item` is yielded once this iterator first returns `None`, after which
+    /// this iterator is never polled again, even if it isn't fused.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn append(self, item: Self::Item) -> Append<Self>
+    where
+        Self: Sized,
+    {
+        Append::new(self, item)
+    }
+
+    /// Maps each element together with its zero-based index, yielding the
+    /// results until `f` returns `None`.
+    ///
+    /// The first `None` ends the iterator without polling the underlying
+    /// iterator again.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn enumerate_map_while<B, F>(self, f: F) -> EnumerateMapWhile<Self, F>
+    where
+        Self: Sized,
+        F: AsyncFnMut(usize, Self::Item) -> Option<B>,
+    {
+        EnumerateMapWhile::new(self, f)
+    }
+
+    /// Creates an iterator over results which yields the `Ok` values in
+    /// `Vec`s of `size` items.
+    ///
+    /// When an error is encountered, the items batched so far are yielded
+    /// along with it in a [`TryChunksError`], and the next batch starts after
+    /// the error. This matches `TryStreamExt::try_chunks` from the `futures`
+    /// crate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn try_chunks<T, E>(self, size: usize) -> TryChunks<Self, T>
+    where
+        Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        TryChunks::new(self, size)
+    }
+}
+
+impl<I: Iterator + ?Sized> Iterator for &mut I {
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        (**self).next().await
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn last_n() {
+        block_on(async {
+            let out = from_iter(0..3).last_n(5).await;
+            assert_eq!(out, vec![0, 1, 2]);
+            assert!(out.capacity() <= 5);
+
+            let out = from_iter(0..5).last_n(5).await;
+            assert_eq!(out, vec![0, 1, 2, 3, 4]);
+            assert!(out.capacity() <= 5);
+
+            let out = from_iter(0..5).last_n(2).await;
+            assert_eq!(out, [3, 4]);
+
+            let out = from_iter(0..10_000).last_n(5).await;
+            assert_eq!(out, vec![9995, 9996, 9997, 9998, 9999]);
+            assert_eq!(out.capacity(), 5);
+        })
+    }
+
+    #[test]
+    fn dropping() {
+        block_on(async {
+            let iter = from_iter(0..5).dropping(2).await;
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec![2, 3, 4]);
+        })
+    }
+
+    #[test]
+    fn fold_ok() {
+        block_on(async {
+            let mut iter = from_iter(vec![Ok(1), Ok(2), Ok(3)]);
+            let sum = iter.fold_ok(0, |a, b| async move { a + b }).await;
+            assert_eq!(sum, Ok::<_, &str>(6));
+
+            let mut iter = from_iter(vec![Ok(1), Err("boom"), Ok(3)]);
+            let sum = iter.fold_ok(0, |a, b| async move { a + b }).await;
+            assert_eq!(sum, Err("boom"));
+            assert_eq!(iter.next().await, Some(Ok(3)));
+        })
+    }
+
+    #[test]
+    fn by_ref() {
+        block_on(async {
+            let mut iter = from_iter(0..5);
+            iter.by_ref().dropping(2).await;
+            let rest: Vec<_> = iter.collect().await;
+            assert_eq!(rest, vec![2, 3, 4]);
+        })
+    }
+
+    #[test]
+    fn top_k() {
+        block_on(async {
+            assert_eq!(from_iter(0..100).top_k(3).await, vec![99, 98, 97]);
+            assert_eq!(from_iter(vec![2, 7, 1]).top_k(5).await, vec![7, 2, 1]);
+        })
+    }
+
+    #[test]
+    fn cloned_and_copied() {
+        block_on(async {
+            let words = vec![String::from("a"), String::from("b")];
+            let out: Vec<String> = from_iter(&words).cloned().collect().await;
+            assert_eq!(out, words);
+
+            let nums = [1, 2, 3];
+            let out: Vec<i32> = from_iter(&nums).copied().collect().await;
+            assert_eq!(out, vec![1, 2, 3]);
+        })
+    }
+
+    #[test]
+    fn collect_array() {
+        block_on(async {
+            let out = from_iter(1..=3).collect_array::<3>().await;
+            assert_eq!(out, Ok([1, 2, 3]));
+
+            let out = from_iter(1..=2).collect_array::<3>().await;
+            assert_eq!(out, Err(vec![1, 2]));
+
+            let mut iter = from_iter(1..=6);
+            let out = (&mut iter).collect_array::<3>().await;
+            assert_eq!(out, Err(vec![1, 2, 3, 4]));
+            assert_eq!(iter.next().await, Some(5));
+        })
+    }
+
+    #[test]
+    fn drain_into() {
+        block_on(async {
+            let mut store = Vec::new();
+            let written = from_iter(0..4)
+                .drain_into(|x| {
+                    store.push(x);
+                    async { Ok::<_, &str>(()) }
+                })
+                .await;
+            assert_eq!(written, Ok(4));
+            assert_eq!(store, vec![0, 1, 2, 3]);
+
+            let written = from_iter(0..4)
+                .drain_into(|x| async move {
+                    if x == 2 {
+                        Err("disk full")
+                    } else {
+                        Ok(())
+                    }
+                })
+                .await;
+            assert_eq!(written, Err((2, "disk full")));
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn collect_map_with() {
+        block_on(async {
+            let map = from_iter(vec![("a", 1), ("a", 2), ("b", 3)])
+                .collect_map_with(async |a: &mut i32, b| *a += b)
+                .await;
+            assert_eq!(map.len(), 2);
+            assert_eq!(map["a"], 3);
+            assert_eq!(map["b"], 3);
+        })
+    }
+
+    #[test]
+    fn sum_kahan() {
+        block_on(async {
+            let n = 1_000_000;
+            let expected = 100_000.0;
+            let naive: f64 = from_iter(core::iter::repeat_n(0.1, n)).sum().await;
+            let kahan = from_iter(core::iter::repeat_n(0.1, n)).sum_kahan().await;
+            assert!((naive - expected).abs() > 1e-8);
+            assert!((kahan - expected).abs() < 1e-9);
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn collect_map_first() {
+        block_on(async {
+            let map = from_iter(vec![("a", 1), ("a", 2), ("b", 3)])
+                .collect_map_first()
+                .await;
+            assert_eq!(map.len(), 2);
+            assert_eq!(map["a"], 1);
+            assert_eq!(map["b"], 3);
+        })
+    }
+
+    /// Records its items and how often it was extended.
+    #[derive(Default)]
+    struct CountingSink {
+        items: Vec<i32>,
+        calls: usize,
+    }
+
+    impl crate::prelude::Extend<i32> for CountingSink {
+        async fn extend<T: crate::IntoIterator<Item = i32>>(&mut self, iter: T) {
+            self.calls += 1;
+            crate::prelude::Extend::extend(&mut self.items, iter).await;
+        }
+    }
+
+    #[test]
+    fn unzip_into() {
+        block_on(async {
+            let mut ids = vec![0];
+            let mut names = String::from("x");
+            from_iter(vec![(1, 'a'), (2, 'b')])
+                .unzip_into(&mut ids, &mut names)
+                .await;
+            assert_eq!(ids, vec![0, 1, 2]);
+            assert_eq!(names, "xab");
+
+            let mut evens = CountingSink::default();
+            let mut odds = CountingSink::default();
+            from_iter((0..70).map(|x| (2 * x, 2 * x + 1)))
+                .unzip_into(&mut evens, &mut odds)
+                .await;
+            assert_eq!(evens.items, (0..70).map(|x| 2 * x).collect::<Vec<_>>());
+            assert_eq!(odds.items, (0..70).map(|x| 2 * x + 1).collect::<Vec<_>>());
+            assert_eq!((evens.calls, odds.calls), (3, 3));
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn group_map() {
+        block_on(async {
+            let words = vec!["apple", "bean", "avocado", "beet", "corn"];
+            let groups = from_iter(words.clone())
+                .group_map(|w| (w.as_bytes()[0], w.len()))
+                .await;
+            assert_eq!(groups.len(), 3);
+            assert_eq!(groups[&b'a'], vec![5, 7]);
+            assert_eq!(groups[&b'b'], vec![4, 4]);
+            assert_eq!(groups[&b'c'], vec![4]);
+
+            let groups = from_iter(words).group_map_by(|_| ()).await;
+            assert_eq!(
+                groups[&()],
+                vec!["apple", "bean", "avocado", "beet", "corn"]
+            );
+
+            let groups = from_iter(Vec::<u8>::new()).group_map_by(|x| *x).await;
+            assert!(groups.is_empty());
+        })
+    }
+
+    #[test]
+    fn concat_bytes() {
+        block_on(async {
+            let chunks: Vec<&[u8]> = vec![b"ab", b"", b"cd"];
+            assert_eq!(from_iter(chunks).concat_bytes().await, b"abcd");
+        })
+    }
+
+    #[test]
+    fn demux() {
+        block_on(async {
+            let mut valid = vec![0];
+            let mut dead_letters = std::collections::VecDeque::from(vec![-1]);
+            from_iter(vec![1, -2, 3, -4, 5])
+                .demux(async |x: &i32| *x > 0, &mut valid, &mut dead_letters)
+                .await;
+            assert_eq!(valid, vec![0, 1, 3, 5]);
+            assert_eq!(dead_letters, vec![-1, -2, -4]);
+
+            let mut small = CountingSink::default();
+            let mut large = CountingSink::default();
+            from_iter(0..100)
+                .demux(async |x: &i32| *x < 80, &mut small, &mut large)
+                .await;
+            assert_eq!(small.items, (0..80).collect::<Vec<_>>());
+            assert_eq!(large.items, (80..100).collect::<Vec<_>>());
+            assert_eq!((small.calls, large.calls), (3, 1));
+        })
+    }
+
+    #[test]
+    fn try_fold() {
+        block_on(async {
+            let sum = from_iter(1..=4)
+                .try_fold(0i32, async |acc, x| acc.checked_add(x))
+                .await;
+            assert_eq!(sum, Some(10));
+
+            let mut iter = from_iter(vec![1, 2, i32::MAX, 3]);
+            let sum = iter.try_fold(0i32, async |acc, x| acc.checked_add(x)).await;
+            assert_eq!(sum, None);
+            assert_eq!(iter.next().await, Some(3));
+
+            let res: Result<u32, String> = from_iter(vec!["1", "2", "x", "4"])
+                .try_fold(0, async |acc, s| {
+                    let n: u32 = s.parse().map_err(|_| format!("bad: {}", s))?;
+                    Ok(acc + n)
+                })
+                .await;
+            assert_eq!(res, Err("bad: x".to_string()));
+        })
+    }
+
+    #[test]
+    fn collect_chunks() {
+        block_on(async {
+            let out = from_iter(0..5).collect_chunks(2).await;
+            assert_eq!(out, vec![vec![0, 1], vec![2, 3], vec![4]]);
+        })
+    }
+
+    #[test]
+    fn all_equal() {
+        block_on(async {
+            assert!(from_iter(vec![2, 2, 2]).all_equal().await);
+            assert!(from_iter(Vec::<i32>::new()).all_equal().await);
+
+            let pulled = core::cell::Cell::new(0);
+            let source = vec![2, 2, 3, 2]
+                .into_iter()
+                .inspect(|_| pulled.set(pulled.get() + 1));
+            assert!(!from_iter(source).all_equal().await);
+            assert_eq!(pulled.get(), 3);
+
+            assert_eq!(from_iter(vec![2, 2]).all_equal_value().await, Ok(Some(2)));
+            assert_eq!(
+                from_iter(Vec::<i32>::new()).all_equal_value().await,
+                Ok(None)
+            );
+            assert_eq!(from_iter(vec![2, 3]).all_equal_value().await, Err((2, 3)));
+        })
+    }
 }
@@ -0,0 +1,85 @@
+use crate::{FusedIterator, Iterator};
+
+/// An iterator that pads another iterator with a fill value up to a minimum
+/// length.
+#[derive(Debug)]
+pub struct PadTo<I: Iterator> {
+    stream: I,
+    /// The number of items still owed to reach the minimum length.
+    remaining: usize,
+    fill: I::Item,
+    done: bool,
+}
+
+impl<I: Iterator> PadTo<I> {
+    pub(crate) fn new(stream: I, len: usize, fill: I::Item) -> Self {
+        Self {
+            stream,
+            remaining: len,
+            fill,
+            done: false,
+        }
+    }
+}
+
+impl<I> Iterator for PadTo<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if !self.done {
+            match self.stream.next().await {
+                Some(item) => {
+                    self.remaining = self.remaining.saturating_sub(1);
+                    return Some(item);
+                }
+                None => self.done = true,
+            }
+        }
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(self.fill.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (self.remaining, Some(self.remaining));
+        }
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.max(self.remaining),
+            upper.map(|upper| upper.max(self.remaining)),
+        )
+    }
+}
+
+impl<I> FusedIterator for PadTo<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn pads_short_sources() {
+        block_on(async {
+            let iter = from_iter(vec![1, 2]).pad_to(4, 0);
+            assert_eq!(iter.size_hint(), (4, Some(4)));
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec![1, 2, 0, 0]);
+
+            let out: Vec<_> = from_iter(vec![1, 2, 3, 4, 5]).pad_to(4, 0).collect().await;
+            assert_eq!(out, vec![1, 2, 3, 4, 5]);
+        })
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{FusedIterator, Iterator};
+
+/// An iterator with a `peek()` that returns a reference to the next element.
+#[derive(Debug)]
+pub struct Peekable<I: Iterator> {
+    stream: I,
+    /// Remembers a peeked value, even if it was `None`.
+    peeked: Option<Option<I::Item>>,
+}
+
+impl<I: Iterator> Peekable<I> {
+    pub(crate) fn new(stream: I) -> Self {
+        Self {
+            stream,
+            peeked: None,
+        }
+    }
+
+    /// Returns a reference to the next value without advancing the iterator.
+    pub async fn peek(&mut self) -> Option<&I::Item> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.stream.next().await);
+        }
+        self.peeked.as_ref().and_then(Option::as_ref)
+    }
+
+    /// Returns a clone of the next value without advancing the iterator.
+    pub async fn peek_cloned(&mut self) -> Option<I::Item>
+    where
+        I::Item: Clone,
+    {
+        self.peek().await.cloned()
+    }
+
+    /// Returns `true` if the iterator has another item, without consuming it.
+    pub async fn has_next(&mut self) -> bool {
+        self.peek().await.is_some()
+    }
+
+    /// Takes the buffered item, if any, without polling the underlying
+    /// iterator.
+    ///
+    /// The next call to `next` or `peek` resumes from the underlying
+    /// iterator.
+    pub fn take_peeked(&mut self) -> Option<I::Item> {
+        self.peeked.take().flatten()
+    }
+
+    /// Consumes the `Peekable`, returning the buffered item, if any, along
+    /// with the underlying iterator.
+    ///
+    /// The underlying iterator resumes after the buffered item, so no items
+    /// are lost by unwrapping the `Peekable`.
+    pub fn into_inner(self) -> (Option<I::Item>, I) {
+        (self.peeked.flatten(), self.stream)
+    }
+}
+
+impl<I: Iterator> Iterator for Peekable<I> {
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some(item) => item,
+            None => self.stream.next().await,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = match &self.peeked {
+            Some(None) => return (0, Some(0)),
+            Some(Some(_)) => 1,
+            None => 0,
+        };
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(peeked),
+            upper.and_then(|upper| upper.checked_add(peeked)),
+        )
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Peekable<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn into_inner_keeps_buffered_item() {
+        block_on(async {
+            let mut iter = from_iter(0..4).peekable();
+            assert_eq!(iter.next().await, Some(0));
+            assert_eq!(iter.peek().await, Some(&1));
+            let (buffered, rest) = iter.into_inner();
+            assert_eq!(buffered, Some(1));
+            let rest: Vec<_> = rest.collect().await;
+            assert_eq!(rest, vec![2, 3]);
+        })
+    }
+
+    #[test]
+    fn has_next() {
+        block_on(async {
+            let mut iter = from_iter(Vec::<u8>::new()).peekable();
+            assert!(!iter.has_next().await);
+            assert_eq!(iter.next().await, None);
+
+            let mut iter = from_iter(vec![String::from("a")]).peekable();
+            assert!(iter.has_next().await);
+            assert!(iter.has_next().await);
+            assert_eq!(iter.peek_cloned().await.as_deref(), Some("a"));
+            assert_eq!(iter.next().await.as_deref(), Some("a"));
+            assert!(!iter.has_next().await);
+        })
+    }
+
+    #[test]
+    fn take_peeked() {
+        block_on(async {
+            let mut iter = from_iter(0..3).peekable();
+            assert_eq!(iter.take_peeked(), None);
+            assert_eq!(iter.peek().await, Some(&0));
+            assert_eq!(iter.take_peeked(), Some(0));
+            assert_eq!(iter.next().await, Some(1));
+        })
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{ExactSizeIterator, FusedIterator, Iterator};
+
+/// An iterator that yields an extra item before the items of another
+/// iterator.
+#[derive(Debug)]
+pub struct Prepend<I: Iterator> {
+    item: Option<I::Item>,
+    stream: I,
+}
+
+impl<I: Iterator> Prepend<I> {
+    pub(crate) fn new(stream: I, item: I::Item) -> Self {
+        Self {
+            item: Some(item),
+            stream,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Prepend<I> {
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        match self.item.take() {
+            Some(item) => Some(item),
+            None => self.stream.next().await,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let extra = usize::from(self.item.is_some());
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(extra),
+            upper.and_then(|upper| upper.checked_add(extra)),
+        )
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Prepend<I> {}
+
+impl<I: FusedIterator> FusedIterator for Prepend<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn prepends_item() {
+        block_on(async {
+            let iter = from_iter(vec!["a", "b"]).prepend("header");
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec!["header", "a", "b"]);
+
+            let out: Vec<_> = from_iter(Vec::<i32>::new()).prepend(1).collect().await;
+            assert_eq!(out, vec![1]);
+        })
+    }
+}
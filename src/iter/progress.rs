@@ -0,0 +1,121 @@
+use crate::{FusedIterator, Iterator};
+
+/// An iterator that reports how many items have been yielded so far.
+#[derive(Debug)]
+pub struct Progress<I, F> {
+    stream: I,
+    every: usize,
+    count: usize,
+    report_final: bool,
+    done: bool,
+    f: F,
+}
+
+impl<I, F> Progress<I, F> {
+    pub(crate) fn new(stream: I, every: usize, f: F) -> Self {
+        Self::with_final(stream, every, true, f)
+    }
+
+    /// Creates a `Progress` that only reports every `every` items, skipping
+    /// the report when the iterator is exhausted.
+    pub(crate) fn without_final(stream: I, every: usize, f: F) -> Self {
+        Self::with_final(stream, every, false, f)
+    }
+
+    fn with_final(stream: I, every: usize, report_final: bool, f: F) -> Self {
+        assert!(every != 0, "progress interval must be greater than zero");
+        Self {
+            stream,
+            every,
+            count: 0,
+            report_final,
+            done: false,
+            f,
+        }
+    }
+}
+
+impl<I, F> Iterator for Progress<I, F>
+where
+    I: Iterator,
+    F: FnMut(usize),
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.stream.next().await {
+            Some(item) => {
+                self.count += 1;
+                if self.count.is_multiple_of(self.every) {
+                    (self.f)(self.count);
+                }
+                Some(item)
+            }
+            None => {
+                self.done = true;
+                if self.report_final && (self.count == 0 || !self.count.is_multiple_of(self.every))
+                {
+                    (self.f)(self.count);
+                }
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.stream.size_hint()
+        }
+    }
+}
+
+impl<I, F> FusedIterator for Progress<I, F>
+where
+    I: Iterator,
+    F: FnMut(usize),
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn cadence() {
+        block_on(async {
+            let mut calls = Vec::new();
+            let out: Vec<_> = from_iter(0..7)
+                .progress(3, |n| calls.push(n))
+                .collect()
+                .await;
+            assert_eq!(out.len(), 7);
+            assert_eq!(calls, vec![3, 6, 7]);
+
+            let mut calls = Vec::new();
+            from_iter(0..6)
+                .progress(2, |n| calls.push(n))
+                .for_each(|_| async {})
+                .await;
+            assert_eq!(calls, vec![2, 4, 6]);
+
+            let mut calls = Vec::new();
+            from_iter(0..0)
+                .progress(2, |n| calls.push(n))
+                .for_each(|_| async {})
+                .await;
+            assert_eq!(calls, vec![0]);
+        })
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_every() {
+        let _ = from_iter(0..1).progress(0, |_| {});
+    }
+}
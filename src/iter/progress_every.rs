@@ -0,0 +1,66 @@
+use super::Progress;
+use crate::{FusedIterator, Iterator};
+
+/// An iterator that reports how many items have been yielded after every
+/// `n` items.
+#[derive(Debug)]
+pub struct ProgressEvery<I, F> {
+    inner: Progress<I, F>,
+}
+
+impl<I, F> ProgressEvery<I, F> {
+    pub(crate) fn new(stream: I, n: usize, f: F) -> Self {
+        Self {
+            inner: Progress::without_final(stream, n, f),
+        }
+    }
+}
+
+impl<I, F> Iterator for ProgressEvery<I, F>
+where
+    I: Iterator,
+    F: FnMut(usize),
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().await
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, F> FusedIterator for ProgressEvery<I, F>
+where
+    I: Iterator,
+    F: FnMut(usize),
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn fires_every_n() {
+        block_on(async {
+            let mut calls = Vec::new();
+            let out: Vec<_> = from_iter(0..10)
+                .progress_every(3, |count| calls.push(count))
+                .collect()
+                .await;
+            assert_eq!(out.len(), 10);
+            assert_eq!(calls, vec![3, 6, 9]);
+
+            let mut calls = Vec::new();
+            from_iter(0..6)
+                .progress_every(3, |count| calls.push(count))
+                .for_each(|_| async {})
+                .await;
+            assert_eq!(calls, vec![3, 6]);
+        })
+    }
+}
@@ -0,0 +1,39 @@
+use crate::Iterator;
+
+/// Yields the `Ok` values of an iterator of results, stopping at the first
+/// error and storing it in `error`.
+pub(crate) struct ResultShunt<'a, I, E> {
+    iter: I,
+    error: &'a mut Option<E>,
+}
+
+impl<'a, I, E> ResultShunt<'a, I, E> {
+    pub(crate) fn new(iter: I, error: &'a mut Option<E>) -> Self {
+        Self { iter, error }
+    }
+}
+
+impl<A, E, I: Iterator<Item = Result<A, E>>> Iterator for ResultShunt<'_, I, E> {
+    type Item = A;
+
+    async fn next(&mut self) -> Option<A> {
+        if self.error.is_some() {
+            return None;
+        }
+        match self.iter.next().await? {
+            Ok(item) => Some(item),
+            Err(err) => {
+                *self.error = Some(err);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.error.is_some() {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
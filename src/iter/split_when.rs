@@ -0,0 +1,141 @@
+use crate::{FusedIterator, Iterator};
+use std::vec::Vec;
+
+/// An iterator that splits another iterator into segments at items matching
+/// a predicate.
+///
+/// Like [`Chunks`](super::Chunks), items are buffered in the iterator itself,
+/// so dropping an in-flight call to `next` doesn't lose them.
+#[derive(Debug)]
+pub struct SplitWhen<I: Iterator, P> {
+    stream: I,
+    predicate: P,
+    keep_delimiter: bool,
+    segment: Vec<I::Item>,
+    /// The item currently being checked by the predicate.
+    candidate: Option<I::Item>,
+    done: bool,
+}
+
+impl<I: Iterator, P> SplitWhen<I, P> {
+    pub(crate) fn new(stream: I, predicate: P) -> Self {
+        Self {
+            stream,
+            predicate,
+            keep_delimiter: false,
+            segment: Vec::new(),
+            candidate: None,
+            done: false,
+        }
+    }
+
+    /// Sets whether the delimiter is kept as the last item of the segment it
+    /// terminates. Delimiters are dropped by default.
+    pub fn keep_delimiter(mut self, keep: bool) -> Self {
+        self.keep_delimiter = keep;
+        self
+    }
+}
+
+impl<I, P> Iterator for SplitWhen<I, P>
+where
+    I: Iterator,
+    P: AsyncFnMut(&I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        loop {
+            if self.candidate.is_none() {
+                match self.stream.next().await {
+                    Some(item) => self.candidate = Some(item),
+                    None => {
+                        self.done = true;
+                        return if self.segment.is_empty() {
+                            None
+                        } else {
+                            Some(core::mem::take(&mut self.segment))
+                        };
+                    }
+                }
+            }
+            let is_delimiter = match &self.candidate {
+                Some(item) => (self.predicate)(item).await,
+                None => false,
+            };
+            let item = self.candidate.take();
+            if is_delimiter {
+                if self.keep_delimiter {
+                    self.segment.extend(item);
+                }
+                return Some(core::mem::take(&mut self.segment));
+            }
+            self.segment.extend(item);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
+
+impl<I, P> FusedIterator for SplitWhen<I, P>
+where
+    I: Iterator,
+    P: AsyncFnMut(&I::Item) -> bool,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::{block_on, cancel_after, Yielding};
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn delimiters() {
+        block_on(async {
+            let out: Vec<_> = from_iter(vec![0, 1, 2, 0, 0, 3, 0])
+                .split_when(async |x: &i32| *x == 0)
+                .collect()
+                .await;
+            assert_eq!(out, vec![vec![], vec![1, 2], vec![], vec![3]]);
+
+            let out: Vec<_> = from_iter(vec![1, 0, 2, 3])
+                .split_when(async |x: &i32| *x == 0)
+                .keep_delimiter(true)
+                .collect()
+                .await;
+            assert_eq!(out, vec![vec![1, 0], vec![2, 3]]);
+        })
+    }
+
+    #[test]
+    fn paragraphs() {
+        block_on(async {
+            let lines = vec!["a", "b", "", "c"];
+            let out: Vec<_> = from_iter(lines)
+                .split_when(async |line: &&str| line.is_empty())
+                .collect()
+                .await;
+            assert_eq!(out, vec![vec!["a", "b"], vec!["c"]]);
+        })
+    }
+
+    #[test]
+    fn cancelled_next_keeps_items() {
+        let mut iter = Yielding::new(4).split_when(async |x: &usize| *x == 2);
+        cancel_after(iter.next(), 2);
+        block_on(async {
+            assert_eq!(iter.next().await, Some(vec![0, 1]));
+            assert_eq!(iter.next().await, Some(vec![3]));
+            assert_eq!(iter.next().await, None);
+        })
+    }
+}
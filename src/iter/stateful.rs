@@ -0,0 +1,68 @@
+use crate::Iterator;
+
+/// An iterator that threads a piece of state through a closure, and keeps
+/// that state accessible after iteration.
+#[derive(Debug)]
+pub struct Stateful<I, St, F> {
+    stream: I,
+    state: St,
+    f: F,
+}
+
+impl<I, St, F> Stateful<I, St, F> {
+    pub(crate) fn new(stream: I, state: St, f: F) -> Self {
+        Self { stream, state, f }
+    }
+
+    /// Returns a reference to the current state.
+    pub fn state(&self) -> &St {
+        &self.state
+    }
+
+    /// Consumes the iterator, returning the current state.
+    pub fn into_state(self) -> St {
+        self.state
+    }
+}
+
+impl<I, St, F, B> Iterator for Stateful<I, St, F>
+where
+    I: Iterator,
+    F: AsyncFnMut(&mut St, I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next().await?;
+        (self.f)(&mut self.state, item).await
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn running_checksum() {
+        block_on(async {
+            let bytes = from_iter(vec![1u8, 2, 3, 4]);
+            let mut iter = bytes.stateful(0u32, async |sum: &mut u32, b: u8| {
+                *sum += u32::from(b);
+                Some(b)
+            });
+
+            assert_eq!(iter.next().await, Some(1));
+            assert_eq!(iter.next().await, Some(2));
+            assert_eq!(*iter.state(), 3);
+
+            let rest: Vec<_> = iter.by_ref().collect().await;
+            assert_eq!(rest, vec![3, 4]);
+            assert_eq!(iter.into_state(), 10);
+        })
+    }
+}
@@ -0,0 +1,59 @@
+use crate::Iterator;
+
+/// An iterator that filters the elements of another iterator with a
+/// predicate which carries state between calls.
+#[derive(Debug)]
+pub struct StatefulFilter<I, St, F> {
+    stream: I,
+    state: St,
+    f: F,
+}
+
+impl<I, St, F> StatefulFilter<I, St, F> {
+    pub(crate) fn new(stream: I, state: St, f: F) -> Self {
+        Self { stream, state, f }
+    }
+}
+
+impl<I, St, F> Iterator for StatefulFilter<I, St, F>
+where
+    I: Iterator,
+    F: AsyncFnMut(&mut St, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.stream.next().await?;
+            if (self.f)(&mut self.state, &item).await {
+                return Some(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn running_total() {
+        block_on(async {
+            let out: Vec<_> = from_iter(vec![1, 2, 3, 4])
+                .stateful_filter(0, async |total: &mut i32, x: &i32| {
+                    // Keep items until the running total exceeds 5.
+                    let keep = *total <= 5;
+                    *total += x;
+                    keep
+                })
+                .collect()
+                .await;
+            assert_eq!(out, vec![1, 2, 3]);
+        })
+    }
+}
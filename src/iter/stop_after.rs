@@ -0,0 +1,128 @@
+use super::deadline::race_deadline;
+use crate::{FusedIterator, Iterator};
+use core::future::Future;
+use core::time::Duration;
+use futures_timer::Delay;
+
+/// An iterator that stops yielding items once a time budget has elapsed.
+#[derive(Debug)]
+pub struct StopAfter<I, D = Delay> {
+    stream: I,
+    budget: Duration,
+    /// Creates the deadline on the first call to `next`.
+    start: fn(Duration) -> D,
+    deadline: Option<D>,
+    done: bool,
+}
+
+impl<I> StopAfter<I> {
+    pub(crate) fn new(stream: I, budget: Duration) -> Self {
+        Self::with_deadline(stream, budget, Delay::new)
+    }
+}
+
+impl<I, D> StopAfter<I, D> {
+    /// Creates the adapter with a custom deadline, so tests can control time.
+    pub(crate) fn with_deadline(stream: I, budget: Duration, start: fn(Duration) -> D) -> Self {
+        Self {
+            stream,
+            budget,
+            start,
+            deadline: None,
+            done: false,
+        }
+    }
+}
+
+impl<I, D> Iterator for StopAfter<I, D>
+where
+    I: Iterator,
+    D: Future<Output = ()> + Unpin,
+{
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let (start, budget) = (self.start, self.budget);
+        let deadline = self.deadline.get_or_insert_with(|| start(budget));
+        match race_deadline(self.stream.next(), deadline).await {
+            Some(Some(item)) => Some(item),
+            Some(None) | None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
+
+impl<I, D> FusedIterator for StopAfter<I, D>
+where
+    I: Iterator,
+    D: Future<Output = ()> + Unpin,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use super::StopAfter;
+    use crate::test::{block_on, Ticks, Yielding};
+    use crate::{from_iter, Iterator};
+    use core::time::Duration;
+
+    /// Yields a single item, then never yields again.
+    struct Stalls(bool);
+
+    impl Iterator for Stalls {
+        type Item = u8;
+
+        async fn next(&mut self) -> Option<u8> {
+            if core::mem::replace(&mut self.0, true) {
+                core::future::pending().await
+            } else {
+                Some(1)
+            }
+        }
+    }
+
+    #[test]
+    fn budget_hit_mid_wait() {
+        block_on(async {
+            let mut iter =
+                StopAfter::with_deadline(Stalls(false), Duration::from_millis(3), Ticks::new);
+            assert_eq!(iter.next().await, Some(1));
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.next().await, None);
+        })
+    }
+
+    #[test]
+    fn items_before_deadline() {
+        block_on(async {
+            // Each item is ready after one `Pending`, which is the only time
+            // the deadline is polled, so every item takes one tick.
+            let iter =
+                StopAfter::with_deadline(Yielding::new(10), Duration::from_millis(2), Ticks::new);
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec![0, 1]);
+        })
+    }
+
+    #[test]
+    fn budget_never_hit() {
+        block_on(async {
+            let iter = from_iter(0..3).stop_after(Duration::from_secs(60));
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec![0, 1, 2]);
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use crate::Iterator;
+use std::time::{Duration, Instant};
+
+/// An iterator that measures how long each call to `next` took to produce
+/// an item.
+#[derive(Debug)]
+pub struct Timed<I> {
+    stream: I,
+    final_wait: Option<Duration>,
+}
+
+impl<I> Timed<I> {
+    pub(crate) fn new(stream: I) -> Self {
+        Self {
+            stream,
+            final_wait: None,
+        }
+    }
+
+    /// Returns how long the call to `next` that observed the end of the
+    /// iterator spent waiting, or `None` if the end hasn't been reached.
+    pub fn final_wait(&self) -> Option<Duration> {
+        self.final_wait
+    }
+}
+
+impl<I: Iterator> Iterator for Timed<I> {
+    type Item = (Duration, I::Item);
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let start = Instant::now();
+        let item = self.stream.next().await;
+        let elapsed = start.elapsed();
+        match item {
+            Some(item) => Some((elapsed, item)),
+            None => {
+                self.final_wait = Some(elapsed);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::Iterator;
+    use std::time::Duration;
+
+    const DELAY: Duration = Duration::from_millis(5);
+
+    /// Yields `0..len`, blocking for `DELAY` on every call to `next`.
+    #[derive(Debug)]
+    struct Slow {
+        next: usize,
+        len: usize,
+    }
+
+    impl Iterator for Slow {
+        type Item = usize;
+
+        async fn next(&mut self) -> Option<usize> {
+            std::thread::sleep(DELAY);
+            if self.next == self.len {
+                return None;
+            }
+            self.next += 1;
+            Some(self.next - 1)
+        }
+    }
+
+    #[test]
+    fn yields_latencies() {
+        block_on(async {
+            let mut iter = Slow { next: 0, len: 2 }.timed();
+            assert_eq!(iter.final_wait(), None);
+
+            let mut items = Vec::new();
+            while let Some((elapsed, item)) = iter.next().await {
+                assert!(elapsed >= DELAY, "{:?} < {:?}", elapsed, DELAY);
+                items.push(item);
+            }
+            assert_eq!(items, vec![0, 1]);
+            assert!(iter.final_wait().unwrap() >= DELAY);
+        })
+    }
+}
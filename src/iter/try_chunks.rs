@@ -0,0 +1,133 @@
+use crate::{FusedIterator, Iterator};
+use core::fmt;
+use std::vec::Vec;
+
+/// An iterator that yields the `Ok` items of another iterator in batches,
+/// stopping a batch early when an error is encountered.
+#[derive(Debug)]
+pub struct TryChunks<I, T> {
+    stream: I,
+    size: usize,
+    buf: Vec<T>,
+    done: bool,
+}
+
+impl<I, T> TryChunks<I, T> {
+    pub(crate) fn new(stream: I, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be greater than zero");
+        Self {
+            stream,
+            size,
+            buf: Vec::new(),
+            done: false,
+        }
+    }
+}
+
+impl<I, T, E> Iterator for TryChunks<I, T>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = Result<Vec<T>, TryChunksError<T, E>>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            self.buf.reserve_exact(self.size);
+        }
+        while !self.done && self.buf.len() < self.size {
+            match self.stream.next().await {
+                Some(Ok(item)) => self.buf.push(item),
+                Some(Err(err)) => {
+                    let partial = core::mem::take(&mut self.buf);
+                    return Some(Err(TryChunksError(partial, err)));
+                }
+                None => self.done = true,
+            }
+        }
+        if self.buf.is_empty() {
+            None
+        } else {
+            Some(Ok(core::mem::take(&mut self.buf)))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buf.len();
+        if self.done {
+            let n = usize::from(buffered > 0);
+            return (n, Some(n));
+        }
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(buffered).div_ceil(self.size);
+        // Every item may be an error, each of which is yielded on its own.
+        (
+            lower,
+            upper.and_then(|n| n.checked_add(usize::from(buffered > 0))),
+        )
+    }
+}
+
+impl<I, T, E> FusedIterator for TryChunks<I, T> where I: Iterator<Item = Result<T, E>> {}
+
+/// An error yielded by [`TryChunks`], carrying the items that were batched
+/// before the error was encountered.
+///
+/// The partial batch is never yielded separately, so no items are lost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryChunksError<T, E>(pub Vec<T>, pub E);
+
+impl<T, E: fmt::Display> fmt::Display for TryChunksError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.1.fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug, E: std::error::Error> std::error::Error for TryChunksError<T, E> {}
+
+#[cfg(test)]
+mod test {
+    use super::TryChunksError;
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn clean_batches() {
+        block_on(async {
+            let out: Vec<_> = from_iter((0..5).map(Ok::<_, &str>))
+                .try_chunks(2)
+                .collect()
+                .await;
+            assert_eq!(out, vec![Ok(vec![0, 1]), Ok(vec![2, 3]), Ok(vec![4])]);
+        })
+    }
+
+    #[test]
+    fn error_flushes_partial_batch() {
+        block_on(async {
+            // An error on the first element of a batch.
+            let out: Vec<_> = from_iter(vec![Ok(1), Ok(2), Err("a"), Ok(3)])
+                .try_chunks(2)
+                .collect()
+                .await;
+            assert_eq!(
+                out,
+                vec![
+                    Ok(vec![1, 2]),
+                    Err(TryChunksError(vec![], "a")),
+                    Ok(vec![3])
+                ]
+            );
+
+            // An error in the middle of a batch.
+            let out: Vec<_> = from_iter(vec![Ok(1), Ok(2), Err("b"), Ok(3), Ok(4)])
+                .try_chunks(3)
+                .collect()
+                .await;
+            assert_eq!(
+                out,
+                vec![Err(TryChunksError(vec![1, 2], "b")), Ok(vec![3, 4])]
+            );
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{IntoIterator, Iterator};
+
+/// An iterator that flattens the `Ok` items of an iterator of results.
+#[derive(Debug)]
+pub struct TryFlatten<I, U: IntoIterator> {
+    stream: I,
+    inner: Option<U::IntoIter>,
+}
+
+impl<I, U: IntoIterator> TryFlatten<I, U> {
+    pub(crate) fn new(stream: I) -> Self {
+        Self {
+            stream,
+            inner: None,
+        }
+    }
+}
+
+impl<I, U, E> Iterator for TryFlatten<I, U>
+where
+    I: Iterator<Item = Result<U, E>>,
+    U: IntoIterator,
+{
+    type Item = Result<U::Item, E>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(inner) = self.inner.as_mut() {
+                match inner.next().await {
+                    Some(item) => return Some(Ok(item)),
+                    None => self.inner = None,
+                }
+            }
+            match self.stream.next().await? {
+                Ok(iter) => self.inner = Some(iter.into_iter().await),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn interleaves_errors() {
+        block_on(async {
+            let iter = from_iter(vec![
+                Ok(from_iter(vec![1, 2])),
+                Err("a"),
+                Ok(from_iter(vec![])),
+                Ok(from_iter(vec![3])),
+                Err("b"),
+                Ok(from_iter(vec![4, 5])),
+            ]);
+            let out: Vec<_> = iter.try_flatten().collect().await;
+            assert_eq!(
+                out,
+                vec![Ok(1), Ok(2), Err("a"), Ok(3), Err("b"), Ok(4), Ok(5)]
+            );
+        })
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{FusedIterator, Iterator};
+
+/// An iterator that skips `Ok` elements of another iterator while a predicate
+/// holds, passing errors through.
+#[derive(Debug)]
+pub struct TrySkipWhile<I, P> {
+    stream: I,
+    predicate: P,
+    done_skipping: bool,
+}
+
+impl<I, P> TrySkipWhile<I, P> {
+    pub(crate) fn new(stream: I, predicate: P) -> Self {
+        Self {
+            stream,
+            predicate,
+            done_skipping: false,
+        }
+    }
+}
+
+impl<I, P, T, E> Iterator for TrySkipWhile<I, P>
+where
+    I: Iterator<Item = Result<T, E>>,
+    P: AsyncFnMut(&T) -> bool,
+{
+    type Item = Result<T, E>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.done_skipping {
+            return self.stream.next().await;
+        }
+        loop {
+            match self.stream.next().await? {
+                Ok(item) => {
+                    if !(self.predicate)(&item).await {
+                        self.done_skipping = true;
+                        return Some(Ok(item));
+                    }
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done_skipping {
+            self.stream.size_hint()
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
+
+impl<I, P, T, E> FusedIterator for TrySkipWhile<I, P>
+where
+    I: FusedIterator<Item = Result<T, E>>,
+    P: AsyncFnMut(&T) -> bool,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    async fn run(input: Vec<Result<i32, &'static str>>) -> Vec<Result<i32, &'static str>> {
+        from_iter(input)
+            .try_skip_while(async |x: &i32| *x < 3)
+            .collect()
+            .await
+    }
+
+    #[test]
+    fn skips_leading_oks() {
+        block_on(async {
+            let out = run(vec![Ok(1), Ok(2), Ok(3), Ok(1)]).await;
+            assert_eq!(out, vec![Ok(3), Ok(1)]);
+        })
+    }
+
+    #[test]
+    fn errors_pass_through() {
+        block_on(async {
+            // Before the predicate flips.
+            let out = run(vec![Ok(1), Err("a"), Ok(2), Ok(3)]).await;
+            assert_eq!(out, vec![Err("a"), Ok(3)]);
+
+            // In place of the item that flips the predicate.
+            let out = run(vec![Ok(1), Err("a"), Ok(4)]).await;
+            assert_eq!(out, vec![Err("a"), Ok(4)]);
+
+            // After the predicate flips.
+            let out = run(vec![Ok(3), Err("a"), Ok(1)]).await;
+            assert_eq!(out, vec![Ok(3), Err("a"), Ok(1)]);
+        })
+    }
+}
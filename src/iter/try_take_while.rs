@@ -0,0 +1,114 @@
+use crate::{FusedIterator, Iterator};
+
+/// An iterator that yields `Ok` elements of another iterator while a
+/// predicate holds, passing errors through.
+#[derive(Debug)]
+pub struct TryTakeWhile<I, P> {
+    stream: I,
+    predicate: P,
+    done_taking: bool,
+}
+
+impl<I, P> TryTakeWhile<I, P> {
+    pub(crate) fn new(stream: I, predicate: P) -> Self {
+        Self {
+            stream,
+            predicate,
+            done_taking: false,
+        }
+    }
+}
+
+impl<I, P, T, E> Iterator for TryTakeWhile<I, P>
+where
+    I: Iterator<Item = Result<T, E>>,
+    P: AsyncFnMut(&T) -> bool,
+{
+    type Item = Result<T, E>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.done_taking {
+            return None;
+        }
+        let Some(item) = self.stream.next().await else {
+            self.done_taking = true;
+            return None;
+        };
+        match item {
+            Ok(item) => {
+                if (self.predicate)(&item).await {
+                    Some(Ok(item))
+                } else {
+                    self.done_taking = true;
+                    None
+                }
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done_taking {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
+
+impl<I, P, T, E> FusedIterator for TryTakeWhile<I, P>
+where
+    I: Iterator<Item = Result<T, E>>,
+    P: AsyncFnMut(&T) -> bool,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::{block_on, Resuming};
+    use crate::{from_iter, Iterator};
+
+    async fn run(input: Vec<Result<i32, &'static str>>) -> Vec<Result<i32, &'static str>> {
+        from_iter(input)
+            .try_take_while(async |x: &i32| *x < 3)
+            .collect()
+            .await
+    }
+
+    #[test]
+    fn takes_leading_oks() {
+        block_on(async {
+            let out = run(vec![Ok(1), Ok(2), Ok(3), Ok(1)]).await;
+            assert_eq!(out, vec![Ok(1), Ok(2)]);
+        })
+    }
+
+    #[test]
+    fn errors_pass_through() {
+        block_on(async {
+            // Before the predicate flips.
+            let out = run(vec![Ok(1), Err("a"), Ok(2), Ok(3)]).await;
+            assert_eq!(out, vec![Ok(1), Err("a"), Ok(2)]);
+
+            // In place of the item that flips the predicate.
+            let out = run(vec![Ok(1), Err("a"), Ok(4), Ok(1)]).await;
+            assert_eq!(out, vec![Ok(1), Err("a")]);
+
+            // After the predicate flips nothing more is yielded.
+            let out = run(vec![Ok(3), Err("a"), Ok(1)]).await;
+            assert_eq!(out, vec![]);
+        })
+    }
+
+    #[test]
+    fn fused_on_source_end() {
+        block_on(async {
+            let mut source = Resuming::new(vec![Some(Ok::<_, ()>(1)), None, Some(Ok(2))]);
+            let mut iter = (&mut source).try_take_while(async |x: &i32| *x < 3);
+            assert_eq!(iter.next().await, Some(Ok(1)));
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.next().await, None);
+            assert_eq!(source.polls, 2);
+        })
+    }
+}
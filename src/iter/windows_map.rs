@@ -0,0 +1,85 @@
+use crate::{FusedIterator, Iterator};
+use std::collections::VecDeque;
+
+/// An iterator that maps each overlapping window of another iterator's
+/// items with a function.
+#[derive(Debug)]
+pub struct WindowsMap<I: Iterator, F> {
+    stream: I,
+    size: usize,
+    // Holds at most `size - 1` items between calls to `next`.
+    window: VecDeque<I::Item>,
+    f: F,
+}
+
+impl<I: Iterator, F> WindowsMap<I, F> {
+    pub(crate) fn new(stream: I, size: usize, f: F) -> Self {
+        assert!(size != 0, "window size must be greater than zero");
+        Self {
+            stream,
+            size,
+            window: VecDeque::with_capacity(size),
+            f,
+        }
+    }
+}
+
+impl<I, F, B> Iterator for WindowsMap<I, F>
+where
+    I: Iterator,
+    F: AsyncFnMut(&[I::Item]) -> B,
+{
+    type Item = B;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        while self.window.len() < self.size {
+            let item = self.stream.next().await?;
+            self.window.push_back(item);
+        }
+        let out = (self.f)(self.window.make_contiguous()).await;
+        self.window.pop_front();
+        Some(out)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.window.len();
+        let (lower, upper) = self.stream.size_hint();
+        let windows = |n: usize| n.saturating_add(buffered).saturating_sub(self.size - 1);
+        (windows(lower), upper.map(windows))
+    }
+}
+
+impl<I, F, B> FusedIterator for WindowsMap<I, F>
+where
+    I: FusedIterator,
+    F: AsyncFnMut(&[I::Item]) -> B,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn moving_sums() {
+        block_on(async {
+            let iter =
+                from_iter(vec![1, 2, 3, 4]).windows_map(2, async |w: &[i32]| w.iter().sum::<i32>());
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec![3, 5, 7]);
+        })
+    }
+
+    #[test]
+    fn shorter_than_window() {
+        block_on(async {
+            let out: Vec<usize> = from_iter(vec![1, 2])
+                .windows_map(3, async |w: &[i32]| w.len())
+                .collect()
+                .await;
+            assert!(out.is_empty());
+        })
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{FusedIterator, Iterator};
+
+/// The position of an element within an iterator.
+///
+/// This `enum` is yielded by the [`WithPosition`] iterator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// The first of several elements.
+    First,
+    /// Neither the first nor the last element.
+    Middle,
+    /// The last of several elements.
+    Last,
+    /// The only element.
+    Only,
+}
+
+/// An iterator that pairs each element of another iterator with its
+/// position.
+#[derive(Debug)]
+pub struct WithPosition<I: Iterator> {
+    stream: I,
+    /// The element to yield next.
+    current: Option<I::Item>,
+    /// The element after `current`, read ahead of time.
+    next: Option<I::Item>,
+    started: bool,
+    yielded: bool,
+    done: bool,
+}
+
+impl<I: Iterator> WithPosition<I> {
+    pub(crate) fn new(stream: I) -> Self {
+        Self {
+            stream,
+            current: None,
+            next: None,
+            started: false,
+            yielded: false,
+            done: false,
+        }
+    }
+
+    async fn pull(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.stream.next().await;
+        self.done = item.is_none();
+        item
+    }
+}
+
+impl<I: Iterator> Iterator for WithPosition<I> {
+    type Item = (Position, I::Item);
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        // Both elements are kept in `self` while reading ahead, so a
+        // cancelled call doesn't lose them.
+        if self.current.is_none() {
+            self.current = if self.started {
+                self.next.take()
+            } else {
+                let item = self.pull().await;
+                self.started = true;
+                item
+            };
+            self.current.as_ref()?;
+        }
+        if self.next.is_none() {
+            self.next = self.pull().await;
+        }
+        let item = self.current.take()?;
+        let first = !core::mem::replace(&mut self.yielded, true);
+        let position = match (first, self.next.is_some()) {
+            (true, true) => Position::First,
+            (false, true) => Position::Middle,
+            (false, false) => Position::Last,
+            (true, false) => Position::Only,
+        };
+        Some((position, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = usize::from(self.current.is_some()) + usize::from(self.next.is_some());
+        if self.done {
+            return (buffered, Some(buffered));
+        }
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(buffered),
+            upper.and_then(|n| n.checked_add(buffered)),
+        )
+    }
+}
+
+impl<I: Iterator> FusedIterator for WithPosition<I> {}
+
+#[cfg(test)]
+mod test {
+    use super::Position;
+    use crate::test::{block_on, cancel_after, Yielding};
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn positions() {
+        block_on(async {
+            let out: Vec<_> = from_iter(vec![1, 2, 3]).with_position().collect().await;
+            assert_eq!(
+                out,
+                vec![
+                    (Position::First, 1),
+                    (Position::Middle, 2),
+                    (Position::Last, 3)
+                ]
+            );
+
+            let out: Vec<_> = from_iter(vec![1]).with_position().collect().await;
+            assert_eq!(out, vec![(Position::Only, 1)]);
+
+            let mut iter = from_iter(Vec::<i32>::new()).with_position();
+            assert_eq!(iter.next().await, None);
+        })
+    }
+
+    #[test]
+    fn cancelled_next_keeps_items() {
+        let mut iter = Yielding::new(2).with_position();
+        cancel_after(iter.next(), 2);
+        block_on(async {
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec![(Position::First, 0), (Position::Last, 1)]);
+        })
+    }
+}
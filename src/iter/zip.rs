@@ -0,0 +1,87 @@
+use crate::Iterator;
+
+/// Zips a tuple of iterators together, yielding tuples of their items.
+///
+/// Iteration stops as soon as any of the iterators is exhausted. Tuples of
+/// two to four iterators are supported.
+pub fn zip_all<T>(iters: T) -> ZipAll<T> {
+    ZipAll { iters }
+}
+
+/// Zips two iterators together, yielding pairs of their items.
+///
+/// This is `zip_all((a, b))` for generic code that already has both
+/// iterators in hand.
+pub fn zip_iters<A: Iterator, B: Iterator>(a: A, b: B) -> ZipAll<(A, B)> {
+    zip_all((a, b))
+}
+
+/// An iterator that iterates a tuple of other iterators simultaneously.
+///
+/// This `struct` is created by the [`zip_all`] function.
+#[derive(Debug)]
+pub struct ZipAll<T> {
+    iters: T,
+}
+
+macro_rules! impl_zip_all {
+    ($($I:ident . $idx:tt),+) => {
+        impl<$($I: Iterator),+> Iterator for ZipAll<($($I,)+)> {
+            type Item = ($($I::Item,)+);
+
+            async fn next(&mut self) -> Option<Self::Item> {
+                Some(($(self.iters.$idx.next().await?,)+))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let mut lower = usize::MAX;
+                let mut upper = None;
+                $(
+                    let (l, u) = self.iters.$idx.size_hint();
+                    lower = lower.min(l);
+                    upper = match (upper, u) {
+                        (Some(a), Some(b)) => Some(core::cmp::min(a, b)),
+                        (a, None) => a,
+                        (None, b) => b,
+                    };
+                )+
+                (lower, upper)
+            }
+        }
+    };
+}
+
+impl_zip_all!(A.0, B.1);
+impl_zip_all!(A.0, B.1, C.2);
+impl_zip_all!(A.0, B.1, C.2, D.3);
+
+#[cfg(test)]
+mod test {
+    use super::{zip_all, zip_iters};
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn shortest_wins() {
+        block_on(async {
+            let iter = zip_all((
+                from_iter(0..3),
+                from_iter(vec!['a', 'b', 'c', 'd']),
+                from_iter(vec![true, false]),
+            ));
+            assert_eq!(iter.size_hint(), (2, Some(2)));
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec![(0, 'a', true), (1, 'b', false)]);
+        })
+    }
+
+    #[test]
+    fn zip_two() {
+        block_on(async {
+            let out: Vec<_> = zip_iters(from_iter(0..3), from_iter(vec!['a', 'b']))
+                .collect()
+                .await;
+            assert_eq!(out, vec![(0, 'a'), (1, 'b')]);
+        })
+    }
+}
@@ -0,0 +1,103 @@
+use super::LendingIterator;
+
+/// An item lent by a [`LendingIterator`] which can be borrowed as `&A`.
+pub trait LentItem<A: ?Sized> {
+    /// Borrows the lent item.
+    fn lent(&self) -> &A;
+}
+
+impl<A: ?Sized> LentItem<A> for &A {
+    fn lent(&self) -> &A {
+        self
+    }
+}
+
+impl<A: ?Sized> LentItem<A> for &mut A {
+    fn lent(&self) -> &A {
+        self
+    }
+}
+
+/// Conversion from a [`LendingIterator`], taking ownership of each lent
+/// item.
+pub trait FromLendingIterator<A: ?Sized>: Sized {
+    /// Creates a value from a lending iterator.
+    async fn from_lending_iter<L>(iter: L) -> Self
+    where
+        L: LendingIterator,
+        for<'a> L::Item<'a>: LentItem<A>;
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A> FromLendingIterator<A> for std::vec::Vec<A::Owned>
+where
+    A: std::borrow::ToOwned + ?Sized,
+{
+    async fn from_lending_iter<L>(mut iter: L) -> Self
+    where
+        L: LendingIterator,
+        for<'a> L::Item<'a>: LentItem<A>,
+    {
+        let mut output = std::vec::Vec::new();
+        while let Some(item) = iter.next().await {
+            output.push(item.lent().to_owned());
+        }
+        output
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl FromLendingIterator<str> for std::string::String {
+    async fn from_lending_iter<L>(mut iter: L) -> Self
+    where
+        L: LendingIterator,
+        for<'a> L::Item<'a>: LentItem<str>,
+    {
+        let mut output = std::string::String::new();
+        while let Some(item) = iter.next().await {
+            output.push_str(item.lent());
+        }
+        output
+    }
+}
+
+#[cfg(all(test, any(feature = "alloc", feature = "std")))]
+mod test {
+    use crate::test::block_on;
+    use crate::LendingIterator;
+
+    /// Lends each line from a single reused buffer.
+    struct Lines {
+        buf: String,
+        lines: std::vec::IntoIter<&'static str>,
+    }
+
+    impl LendingIterator for Lines {
+        type Item<'a> = &'a str;
+
+        async fn next(&mut self) -> Option<Self::Item<'_>> {
+            let line = self.lines.next()?;
+            self.buf.clear();
+            self.buf.push_str(line);
+            Some(&self.buf)
+        }
+    }
+
+    fn lines() -> Lines {
+        Lines {
+            buf: String::new(),
+            lines: vec!["GET /", "Host: a", ""].into_iter(),
+        }
+    }
+
+    #[test]
+    fn collect_owned() {
+        block_on(async {
+            let out: Vec<String> = lines().collect_owned().await;
+            assert_eq!(out, vec!["GET /", "Host: a", ""]);
+
+            let out: String = lines().collect_owned().await;
+            assert_eq!(out, "GET /Host: a");
+        })
+    }
+}
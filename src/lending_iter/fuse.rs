@@ -0,0 +1,82 @@
+use super::{FusedLendingIterator, LendingIterator};
+
+/// A lending iterator that yields `None` forever after the underlying
+/// iterator yields `None` once.
+#[derive(Debug)]
+pub struct LendingFuse<L> {
+    iter: L,
+    done: bool,
+}
+
+impl<L> LendingFuse<L> {
+    pub(crate) fn new(iter: L) -> Self {
+        Self { iter, done: false }
+    }
+}
+
+impl<L: LendingIterator> LendingIterator for LendingFuse<L> {
+    type Item<'a>
+        = L::Item<'a>
+    where
+        Self: 'a;
+
+    async fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next().await;
+        if item.is_none() {
+            self.done = true;
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+}
+
+impl<L: LendingIterator> FusedLendingIterator for LendingFuse<L> {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::LendingIterator;
+
+    /// Lends a slice of its buffer, and resumes lending after returning `None`.
+    struct Flaky {
+        buf: [u8; 4],
+        calls: usize,
+    }
+
+    impl LendingIterator for Flaky {
+        type Item<'a> = &'a [u8];
+
+        async fn next(&mut self) -> Option<Self::Item<'_>> {
+            self.calls += 1;
+            match self.calls {
+                2 => None,
+                n => Some(&self.buf[..n % 4]),
+            }
+        }
+    }
+
+    #[test]
+    fn sticky_none() {
+        block_on(async {
+            let mut iter = Flaky {
+                buf: [1, 2, 3, 4],
+                calls: 0,
+            }
+            .fuse();
+            assert_eq!(iter.next().await, Some(&[1][..]));
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.next().await, None);
+        })
+    }
+}
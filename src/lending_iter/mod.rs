@@ -1,5 +1,12 @@
-/// An interface for dealing with iterators which borrow from `Self`
+mod from_lending_iterator;
+mod fuse;
+mod take_while;
+
+pub use from_lending_iterator::{FromLendingIterator, LentItem};
+pub use fuse::LendingFuse;
+pub use take_while::{LendingMapWhile, LendingTakeWhile};
 
+/// An interface for dealing with iterators which borrow from `Self`
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub trait LendingIterator {
     /// The type of the elements being iterated over.
@@ -9,4 +16,144 @@ pub trait LendingIterator {
 
     /// Advances the iterator and returns the next value.
     async fn next(&mut self) -> Option<Self::Item<'_>>;
+
+    /// Returns the bounds on the remaining length of the iterator.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+
+    /// Borrows a lending iterator, rather than consuming it.
+    ///
+    /// This is useful to allow applying adapters while still retaining
+    /// ownership of the original lending iterator.
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    /// Creates a lending iterator which ends after the first `None`.
+    ///
+    /// Sources which lend from reused buffers may not be valid to advance
+    /// after they have returned `None`; this guarantees they never are.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn fuse(self) -> LendingFuse<Self>
+    where
+        Self: Sized,
+    {
+        LendingFuse::new(self)
+    }
+
+    /// Creates a lending iterator which yields items while `predicate`
+    /// returns `true`.
+    ///
+    /// Once `predicate` rejects an item, that item is dropped and the
+    /// iterator returns `None` without advancing the source again.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn take_while<P>(self, predicate: P) -> LendingTakeWhile<Self, P>
+    where
+        Self: Sized,
+        P: for<'a> FnMut(&Self::Item<'a>) -> bool,
+    {
+        LendingTakeWhile::new(self, predicate)
+    }
+
+    /// Creates a lending iterator which maps items with `f` while it returns
+    /// `Some`.
+    ///
+    /// Once `f` returns `None`, the iterator returns `None` without advancing
+    /// the source again.
+    #[must_use = "iterators do nothing unless iterated over"]
+    fn map_while<B, F>(self, f: F) -> LendingMapWhile<Self, F>
+    where
+        Self: Sized,
+        F: for<'a> FnMut(Self::Item<'a>) -> Option<B>,
+    {
+        LendingMapWhile::new(self, f)
+    }
+
+    /// Transforms a lending iterator into a collection, taking ownership of
+    /// each lent item.
+    async fn collect_owned<A, B>(self) -> B
+    where
+        Self: Sized,
+        A: ?Sized,
+        B: FromLendingIterator<A>,
+        for<'a> Self::Item<'a>: LentItem<A>,
+    {
+        B::from_lending_iter(self).await
+    }
+}
+
+/// A lending iterator that always continues to yield `None` when exhausted.
+pub trait FusedLendingIterator: LendingIterator {}
+
+impl<L: LendingIterator + ?Sized> LendingIterator for &mut L {
+    type Item<'a>
+        = L::Item<'a>
+    where
+        Self: 'a;
+
+    async fn next(&mut self) -> Option<Self::Item<'_>> {
+        (**self).next().await
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
+
+impl<L: FusedLendingIterator + ?Sized> FusedLendingIterator for &mut L {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::LendingIterator;
+
+    /// Lends each of `0..5` from a reused one-byte buffer.
+    struct Numbers {
+        n: u8,
+        buf: [u8; 1],
+    }
+
+    impl LendingIterator for Numbers {
+        type Item<'a> = &'a [u8];
+
+        async fn next(&mut self) -> Option<Self::Item<'_>> {
+            if self.n == 5 {
+                return None;
+            }
+            self.buf[0] = self.n;
+            self.n += 1;
+            Some(&self.buf[..])
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let n = usize::from(5 - self.n);
+            (n, Some(n))
+        }
+    }
+
+    async fn skip_one<L: LendingIterator>(mut lender: L) {
+        lender.next().await;
+    }
+
+    #[test]
+    fn by_ref() {
+        block_on(async {
+            let mut lender = Numbers { n: 0, buf: [0] };
+            {
+                let mut borrowed = lender.by_ref().fuse();
+                assert_eq!(borrowed.next().await, Some(&[0][..]));
+                assert_eq!(borrowed.next().await, Some(&[1][..]));
+                assert_eq!(borrowed.next().await, Some(&[2][..]));
+            }
+            assert_eq!(lender.size_hint(), (2, Some(2)));
+
+            skip_one(&mut lender).await;
+            assert_eq!(lender.next().await, Some(&[4][..]));
+            assert_eq!(lender.next().await, None);
+        })
+    }
 }
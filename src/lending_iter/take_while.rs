@@ -0,0 +1,175 @@
+use super::{FusedLendingIterator, LendingIterator};
+
+/// A lending iterator that only yields items while a predicate holds.
+#[derive(Debug)]
+pub struct LendingTakeWhile<L, P> {
+    iter: L,
+    predicate: P,
+    done: bool,
+}
+
+impl<L, P> LendingTakeWhile<L, P> {
+    pub(crate) fn new(iter: L, predicate: P) -> Self {
+        Self {
+            iter,
+            predicate,
+            done: false,
+        }
+    }
+}
+
+impl<L, P> LendingIterator for LendingTakeWhile<L, P>
+where
+    L: LendingIterator,
+    P: for<'a> FnMut(&L::Item<'a>) -> bool,
+{
+    type Item<'a>
+        = L::Item<'a>
+    where
+        Self: 'a;
+
+    async fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.done {
+            return None;
+        }
+        // Borrow `iter` and `done` separately, so the rejected item's borrow
+        // of `iter` doesn't prevent marking the iterator as finished.
+        let Self {
+            iter,
+            predicate,
+            done,
+        } = self;
+        match iter.next().await {
+            Some(item) if predicate(&item) => Some(item),
+            _ => {
+                *done = true;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<L, P> FusedLendingIterator for LendingTakeWhile<L, P>
+where
+    L: LendingIterator,
+    P: for<'a> FnMut(&L::Item<'a>) -> bool,
+{
+}
+
+/// A lending iterator that maps items with a function until it returns
+/// `None`.
+#[derive(Debug)]
+pub struct LendingMapWhile<L, F> {
+    iter: L,
+    f: F,
+    done: bool,
+}
+
+impl<L, F> LendingMapWhile<L, F> {
+    pub(crate) fn new(iter: L, f: F) -> Self {
+        Self {
+            iter,
+            f,
+            done: false,
+        }
+    }
+}
+
+impl<L, F, B> LendingIterator for LendingMapWhile<L, F>
+where
+    L: LendingIterator,
+    F: for<'a> FnMut(L::Item<'a>) -> Option<B>,
+{
+    type Item<'a>
+        = B
+    where
+        Self: 'a;
+
+    async fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.done {
+            return None;
+        }
+        // The lent item is moved into `f`, so its borrow of `iter` ends
+        // before `done` is written.
+        let out = self.iter.next().await.and_then(&mut self.f);
+        if out.is_none() {
+            self.done = true;
+        }
+        out
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<L, F, B> FusedLendingIterator for LendingMapWhile<L, F>
+where
+    L: LendingIterator,
+    F: for<'a> FnMut(L::Item<'a>) -> Option<B>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::LendingIterator;
+
+    /// Parses records into a reused buffer, and keeps lending records after
+    /// the sentinel.
+    struct Records {
+        buf: [u8; 8],
+        input: std::vec::IntoIter<&'static [u8]>,
+    }
+
+    impl LendingIterator for Records {
+        type Item<'a> = &'a [u8];
+
+        async fn next(&mut self) -> Option<Self::Item<'_>> {
+            let record = self.input.next()?;
+            self.buf[..record.len()].copy_from_slice(record);
+            Some(&self.buf[..record.len()])
+        }
+    }
+
+    fn records() -> Records {
+        Records {
+            buf: [0; 8],
+            input: vec![&b"ab"[..], b"cde", b"", b"fg"].into_iter(),
+        }
+    }
+
+    #[test]
+    fn take_while_stops_at_sentinel() {
+        block_on(async {
+            let mut iter = records().take_while(|record| !record.is_empty());
+            assert_eq!(iter.next().await, Some(&b"ab"[..]));
+            assert_eq!(iter.next().await, Some(&b"cde"[..]));
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.next().await, None);
+        })
+    }
+
+    #[test]
+    fn map_while_stops_at_sentinel() {
+        block_on(async {
+            let mut iter = records().map_while(|record| record.first().copied());
+            assert_eq!(iter.next().await, Some(b'a'));
+            assert_eq!(iter.next().await, Some(b'c'));
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.next().await, None);
+        })
+    }
+}
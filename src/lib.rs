@@ -23,32 +23,211 @@
 #![deny(missing_debug_implementations, nonstandard_style)]
 #![warn(missing_docs)]
 
+mod exact_size_iterator;
 mod extend;
 mod from_iterator;
+mod fused_iterator;
 mod into_iterator;
 mod iter;
 mod lending_iter;
+mod sources;
+mod sum;
+mod try_extend;
+mod try_trait;
 
+pub use exact_size_iterator::ExactSizeIterator;
 pub use from_iterator::FromIterator;
+pub use fused_iterator::FusedIterator;
 pub use into_iterator::IntoIterator;
-pub use lending_iter::LendingIterator;
+pub use lending_iter::{
+    FromLendingIterator, FusedLendingIterator, LendingFuse, LendingIterator, LendingMapWhile,
+    LendingTakeWhile, LentItem,
+};
+pub use sum::{Product, Sum};
+pub use try_trait::Try;
 
-pub use iter::{Iterator, Lend, LendMut, Map};
+pub use iter::{
+    zip_all, zip_iters, Append, Batching, Chain, Cloned, Coalesce, Copied, CycleN, Delta,
+    EitherIter, Enumerate, EnumerateMapWhile, ErrContext, ErrInto, Filter, FilterSync,
+    FlattenOptions, Intersperse, Iterator, Lend, LendMut, LendWith, Map, MapControl, MapRetry,
+    MapTap, MaybeFilter, MaybeMap, PadTo, Peekable, Position, Prepend, Progress, ProgressEvery,
+    Stateful, StatefulFilter, TryFlatten, TrySkipWhile, TryTakeWhile, WithPosition, ZipAll,
+};
+pub use sources::{
+    bytes, chars, from_iter, repeat_n, try_unfold, unfold, Bytes, Chars, FromIter, RepeatN,
+    TryUnfold, Unfold,
+};
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use iter::{
+    chain_many, kmerge, AssertItems, BufferWhile, ChainMany, Chunks, ChunksByWeight,
+    FlatMapUnordered, FlattenUnordered, FrameLines, KMerge, Lookahead, SplitWhen, TryChunks,
+    TryChunksError, WindowsMap,
+};
+
+#[cfg(feature = "std")]
+pub use sources::{from_receiver, ChannelIter};
+
+#[cfg(feature = "std")]
+pub use iter::{Instrumented, Meter, Metered, Stats, Timed};
+
+#[cfg(feature = "timer")]
+pub use iter::StopAfter;
 
 /// The `async-iterator` prelude
 pub mod prelude {
-    pub use crate::extend::Extend;
+    pub use crate::extend::{Extend, MergeExtend};
     pub use crate::from_iterator::FromIterator;
     pub use crate::into_iterator::IntoIterator;
+    pub use crate::sum::{Product, Sum};
+    pub use crate::try_extend::TryExtend;
 }
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 extern crate alloc as std;
 
-#[cfg(all(test, feature = "alloc"))]
+/// Collecting into `alloc` collections must keep compiling in `no_std`
+/// builds, where `std` is an alias for the `alloc` crate.
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+#[allow(dead_code)]
+mod alloc_smoke {
+    use crate::prelude::*;
+    use crate::{chars, from_iter, Iterator};
+    use std::boxed::Box;
+    use std::collections::VecDeque;
+    use std::string::String;
+    use std::vec::Vec;
+
+    async fn collect_vec() -> Vec<u8> {
+        from_iter(0..3).collect().await
+    }
+
+    async fn collect_string() -> String {
+        let mut s: String = chars("ab").collect().await;
+        Extend::extend(&mut s, from_iter(["c", "d"])).await;
+        s
+    }
+
+    async fn collect_other() -> (VecDeque<u8>, Box<[u8]>) {
+        let mut deque: VecDeque<u8> = from_iter(0..3).collect().await;
+        Extend::extend(&mut deque, from_iter(3..6)).await;
+        (deque, from_iter(0..3).collect().await)
+    }
+}
+
+#[cfg(test)]
 mod test {
     pub use super::*;
 
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    /// Drives a future to completion on the current thread.
+    pub(crate) fn block_on<F: Future>(fut: F) -> F::Output {
+        let mut fut = pin!(fut);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+                return out;
+            }
+        }
+    }
+
+    /// Polls a future `polls` times, asserting it stays pending, then drops
+    /// it to simulate cancellation.
+    pub(crate) fn cancel_after<F: Future>(fut: F, polls: usize) {
+        let mut fut = pin!(fut);
+        let mut cx = Context::from_waker(Waker::noop());
+        for _ in 0..polls {
+            assert!(fut.as_mut().poll(&mut cx).is_pending());
+        }
+    }
+
+    /// A deadline that treats every poll as one elapsed millisecond, so
+    /// tests control time by how often they poll.
+    #[cfg(feature = "timer")]
+    #[derive(Debug)]
+    pub(crate) struct Ticks(u128);
+
+    #[cfg(feature = "timer")]
+    impl Ticks {
+        pub(crate) fn new(dur: core::time::Duration) -> Self {
+            Self(dur.as_millis())
+        }
+    }
+
+    #[cfg(feature = "timer")]
+    impl Future for Ticks {
+        type Output = ();
+
+        fn poll(mut self: core::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 == 0 {
+                return Poll::Ready(());
+            }
+            self.0 -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    /// Yields `0..len`, returning `Pending` once before each item.
+    pub(crate) struct Yielding {
+        next: usize,
+        len: usize,
+    }
+
+    impl Yielding {
+        pub(crate) fn new(len: usize) -> Self {
+            Self { next: 0, len }
+        }
+    }
+
+    impl Iterator for Yielding {
+        type Item = usize;
+
+        async fn next(&mut self) -> Option<usize> {
+            let mut yielded = false;
+            core::future::poll_fn(|cx| {
+                if core::mem::replace(&mut yielded, true) {
+                    Poll::Ready(())
+                } else {
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+            })
+            .await;
+            self.next += 1;
+            (self.next <= self.len).then_some(self.next - 1)
+        }
+    }
+
+    /// Yields the entries of a script, which may contain `None` before more
+    /// items, and counts how often it was polled.
+    pub(crate) struct Resuming<T> {
+        script: std::vec::IntoIter<Option<T>>,
+        pub(crate) polls: usize,
+    }
+
+    impl<T> Resuming<T> {
+        pub(crate) fn new(script: std::vec::Vec<Option<T>>) -> Self {
+            Self {
+                script: script.into_iter(),
+                polls: 0,
+            }
+        }
+    }
+
+    impl<T> Iterator for Resuming<T> {
+        type Item = T;
+
+        async fn next(&mut self) -> Option<T> {
+            self.polls += 1;
+            self.script.next().flatten()
+        }
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
     #[test]
     fn smoke() {
         #[allow(dead_code)]
@@ -0,0 +1,74 @@
+use crate::{FusedIterator, Iterator};
+use core::future::poll_fn;
+use core::task::Poll;
+use std::sync::mpsc::{Receiver, TryRecvError};
+
+/// Converts the receiving half of a [`std::sync::mpsc`] channel into an
+/// async iterator.
+///
+/// The iterator yields every value sent on the channel, and ends once all
+/// senders have been dropped.
+///
+/// `Receiver::recv` blocks the thread, so the iterator checks the channel
+/// with `try_recv` instead. When the channel is empty it returns `Pending`
+/// and immediately wakes itself, which keeps the executor busy while no
+/// values arrive. For long waits, prefer receiving on a blocking thread
+/// and forwarding through an async channel.
+pub fn from_receiver<T>(rx: Receiver<T>) -> ChannelIter<T> {
+    ChannelIter { rx, done: false }
+}
+
+/// An async iterator over the values received on a channel.
+///
+/// This `struct` is created by the [`from_receiver`] function.
+#[derive(Debug)]
+pub struct ChannelIter<T> {
+    rx: Receiver<T>,
+    done: bool,
+}
+
+impl<T> Iterator for ChannelIter<T> {
+    type Item = T;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        poll_fn(|cx| match self.rx.try_recv() {
+            Ok(value) => Poll::Ready(Some(value)),
+            Err(TryRecvError::Disconnected) => {
+                self.done = true;
+                Poll::Ready(None)
+            }
+            Err(TryRecvError::Empty) => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
+
+impl<T> FusedIterator for ChannelIter<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::from_receiver;
+    use crate::test::block_on;
+    use crate::Iterator;
+    use std::sync::mpsc;
+    use std::thread;
+
+    #[test]
+    fn receives_until_disconnect() {
+        let (tx, rx) = mpsc::channel();
+        let producer = thread::spawn(move || {
+            for value in 1..=3 {
+                tx.send(value).unwrap();
+            }
+        });
+        let out: Vec<_> = block_on(from_receiver(rx).collect());
+        producer.join().unwrap();
+        assert_eq!(out, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{ExactSizeIterator, FusedIterator, Iterator};
+
+/// Converts a synchronous iterator into an async iterator.
+pub fn from_iter<I: core::iter::IntoIterator>(iter: I) -> FromIter<I::IntoIter> {
+    FromIter {
+        iter: iter.into_iter(),
+    }
+}
+
+/// An async iterator over the items of a synchronous iterator.
+///
+/// This `struct` is created by the [`from_iter`] function.
+#[derive(Debug, Clone)]
+pub struct FromIter<I> {
+    iter: I,
+}
+
+impl<'a, T> FromIter<core::slice::Iter<'a, T>> {
+    /// Returns the remaining items of the underlying slice.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.iter.as_slice()
+    }
+
+    /// Clones the remaining items of the underlying slice into a `Vec`.
+    ///
+    /// This is equivalent to `self.cloned().collect()`, but copies the
+    /// remaining items in bulk rather than one at a time, which for `Copy`
+    /// items is a single `memcpy`.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn to_vec(self) -> std::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter.as_slice().to_vec()
+    }
+}
+
+impl<I: core::iter::Iterator> Iterator for FromIter<I> {
+    type Item = I::Item;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: core::iter::ExactSizeIterator> ExactSizeIterator for FromIter<I> {}
+
+impl<I: core::iter::FusedIterator> FusedIterator for FromIter<I> {}
+
+#[cfg(all(test, any(feature = "alloc", feature = "std")))]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn slice_to_vec() {
+        block_on(async {
+            let data = [1u32, 2, 3, 4, 5];
+            let mut iter = from_iter(data.iter());
+            iter.next().await;
+            assert_eq!(iter.as_slice(), &data[1..]);
+
+            let expected: Vec<u32> = from_iter(data.iter()).copied().collect().await;
+            assert_eq!(from_iter(data.iter()).to_vec(), expected);
+        })
+    }
+}
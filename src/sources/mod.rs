@@ -0,0 +1,15 @@
+#[cfg(feature = "std")]
+mod channel;
+mod from_iter;
+mod repeat;
+mod str;
+mod try_unfold;
+mod unfold;
+
+#[cfg(feature = "std")]
+pub use channel::{from_receiver, ChannelIter};
+pub use from_iter::{from_iter, FromIter};
+pub use repeat::{repeat_n, RepeatN};
+pub use str::{bytes, chars, Bytes, Chars};
+pub use try_unfold::{try_unfold, TryUnfold};
+pub use unfold::{unfold, Unfold};
@@ -0,0 +1,63 @@
+use crate::{ExactSizeIterator, FusedIterator, Iterator};
+
+/// Creates an async iterator that yields an element exactly `n` times.
+pub fn repeat_n<T: Clone>(element: T, n: usize) -> RepeatN<T> {
+    RepeatN {
+        element: if n == 0 { None } else { Some(element) },
+        count: n,
+    }
+}
+
+/// An async iterator that repeats an element a fixed number of times.
+///
+/// This `struct` is created by the [`repeat_n`] function.
+#[derive(Debug, Clone)]
+pub struct RepeatN<T> {
+    element: Option<T>,
+    count: usize,
+}
+
+impl<T: Clone> Iterator for RepeatN<T> {
+    type Item = T;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.count == 0 {
+            return None;
+        }
+        self.count -= 1;
+        if self.count == 0 {
+            // The last element can be moved out rather than cloned.
+            self.element.take()
+        } else {
+            self.element.clone()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count, Some(self.count))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for RepeatN<T> {}
+
+impl<T: Clone> FusedIterator for RepeatN<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::repeat_n;
+    use crate::test::block_on;
+    use crate::Iterator;
+
+    #[test]
+    fn repeats_n_times() {
+        block_on(async {
+            let iter = repeat_n('x', 3);
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+            let out: Vec<_> = iter.collect().await;
+            assert_eq!(out, vec!['x', 'x', 'x']);
+
+            let mut iter = repeat_n('x', 0);
+            assert_eq!(iter.next().await, None);
+        })
+    }
+}
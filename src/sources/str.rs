@@ -0,0 +1,96 @@
+use crate::{ExactSizeIterator, FusedIterator, Iterator};
+
+/// Creates an async iterator over the `char`s of a string slice.
+pub fn chars(s: &str) -> Chars<'_> {
+    Chars { inner: s.chars() }
+}
+
+/// Creates an async iterator over the bytes of a string slice.
+pub fn bytes(s: &str) -> Bytes<'_> {
+    Bytes { inner: s.bytes() }
+}
+
+/// An async iterator over the `char`s of a string slice.
+///
+/// This `struct` is created by the [`chars`] function.
+#[derive(Debug, Clone)]
+pub struct Chars<'a> {
+    inner: core::str::Chars<'a>,
+}
+
+impl<'a> Chars<'a> {
+    /// Views the remaining, not yet yielded part of the string.
+    pub fn as_str(&self) -> &'a str {
+        self.inner.as_str()
+    }
+}
+
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every `char` takes between one and four bytes.
+        let len = self.inner.as_str().len();
+        (len.div_ceil(4), Some(len))
+    }
+}
+
+impl FusedIterator for Chars<'_> {}
+
+/// An async iterator over the bytes of a string slice.
+///
+/// This `struct` is created by the [`bytes`] function.
+#[derive(Debug, Clone)]
+pub struct Bytes<'a> {
+    inner: core::str::Bytes<'a>,
+}
+
+impl Iterator for Bytes<'_> {
+    type Item = u8;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Bytes<'_> {}
+
+impl FusedIterator for Bytes<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::{bytes, chars};
+    use crate::test::block_on;
+    use crate::Iterator;
+
+    #[test]
+    fn multi_byte_chars() {
+        block_on(async {
+            let mut iter = chars("aé€😀");
+            assert_eq!(iter.size_hint(), (3, Some(10)));
+            assert_eq!(iter.next().await, Some('a'));
+            assert_eq!(iter.next().await, Some('é'));
+            assert_eq!(iter.as_str(), "€😀");
+            let rest: String = iter.collect().await;
+            assert_eq!(rest, "€😀");
+        })
+    }
+
+    #[test]
+    fn bytes_are_exact() {
+        block_on(async {
+            let iter = bytes("é€");
+            assert_eq!(iter.size_hint(), (5, Some(5)));
+            let out: Vec<u8> = iter.collect().await;
+            assert_eq!(out, "é€".as_bytes());
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{FusedIterator, Iterator};
+
+/// Creates an async iterator from a seed and a fallible async closure.
+///
+/// `f` is called with the current state and returns the next item along with
+/// the state for the following call, `Ok(None)` to end the iterator, or an
+/// error. An error is yielded once, after which the iterator ends; `f` is
+/// never called again after it returns an error or `Ok(None)`.
+pub fn try_unfold<St, T, E, F>(init: St, f: F) -> TryUnfold<St, F>
+where
+    F: AsyncFnMut(St) -> Result<Option<(T, St)>, E>,
+{
+    TryUnfold {
+        state: Some(init),
+        f,
+    }
+}
+
+/// An async iterator built from a seed and a fallible async closure.
+///
+/// This `struct` is created by the [`try_unfold`] function.
+#[derive(Debug)]
+pub struct TryUnfold<St, F> {
+    /// `None` once the iterator has ended.
+    state: Option<St>,
+    f: F,
+}
+
+impl<St, T, E, F> Iterator for TryUnfold<St, F>
+where
+    F: AsyncFnMut(St) -> Result<Option<(T, St)>, E>,
+{
+    type Item = Result<T, E>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let state = self.state.take()?;
+        match (self.f)(state).await {
+            Ok(Some((item, state))) => {
+                self.state = Some(state);
+                Some(Ok(item))
+            }
+            Ok(None) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.state {
+            Some(_) => (0, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<St, T, E, F> FusedIterator for TryUnfold<St, F> where
+    F: AsyncFnMut(St) -> Result<Option<(T, St)>, E>
+{
+}
+
+#[cfg(test)]
+mod test {
+    use super::try_unfold;
+    use crate::test::block_on;
+    use crate::Iterator;
+
+    /// Fetches pages `page..3`, failing on page `fail_at`.
+    fn pages(fail_at: u32) -> impl Iterator<Item = Result<u32, &'static str>> {
+        try_unfold(0, async move |page| match page {
+            p if p == fail_at => Err("timeout"),
+            3 => Ok(None),
+            p => Ok(Some((p * 10, p + 1))),
+        })
+    }
+
+    #[test]
+    fn clean_termination() {
+        block_on(async {
+            let out: Vec<_> = pages(u32::MAX).collect().await;
+            assert_eq!(out, vec![Ok(0), Ok(10), Ok(20)]);
+        })
+    }
+
+    #[test]
+    fn error_ends_iteration() {
+        block_on(async {
+            let mut calls = 0;
+            let mut iter = try_unfold(0, |page: u32| {
+                calls += 1;
+                async move {
+                    match page {
+                        1 => Err("timeout"),
+                        p => Ok(Some((p, p + 1))),
+                    }
+                }
+            });
+            assert_eq!(iter.next().await, Some(Ok(0)));
+            assert_eq!(iter.next().await, Some(Err("timeout")));
+            assert_eq!(iter.next().await, None);
+            assert_eq!(iter.next().await, None);
+            assert_eq!(calls, 2);
+
+            let out: Vec<_> = pages(2).collect().await;
+            assert_eq!(out, vec![Ok(0), Ok(10), Err("timeout")]);
+        })
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{FusedIterator, Iterator};
+
+/// Creates an async iterator from a seed and an async closure.
+///
+/// `f` is called with the current state and returns the next item along with
+/// the state for the following call, or `None` to end the iterator. `f` is
+/// never called again after it returns `None`.
+pub fn unfold<St, T, F>(init: St, f: F) -> Unfold<St, F>
+where
+    F: AsyncFnMut(St) -> Option<(T, St)>,
+{
+    Unfold {
+        state: Some(init),
+        f,
+    }
+}
+
+/// An async iterator built from a seed and an async closure.
+///
+/// This `struct` is created by the [`unfold`] function.
+#[derive(Debug)]
+pub struct Unfold<St, F> {
+    /// `None` once the iterator has ended.
+    state: Option<St>,
+    f: F,
+}
+
+impl<St, T, F> Iterator for Unfold<St, F>
+where
+    F: AsyncFnMut(St) -> Option<(T, St)>,
+{
+    type Item = T;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        let state = self.state.take()?;
+        let (item, state) = (self.f)(state).await?;
+        self.state = Some(state);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.state {
+            Some(_) => (0, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<St, T, F> FusedIterator for Unfold<St, F> where F: AsyncFnMut(St) -> Option<(T, St)> {}
+
+#[cfg(test)]
+mod test {
+    use super::unfold;
+    use crate::test::block_on;
+    use crate::Iterator;
+
+    #[test]
+    fn fibonacci() {
+        block_on(async {
+            let mut iter = unfold((0u32, 1u32), async |(a, b)| {
+                if a > 50 {
+                    None
+                } else {
+                    Some((a, (b, a + b)))
+                }
+            });
+            let mut out = Vec::new();
+            while let Some(n) = iter.next().await {
+                out.push(n);
+            }
+            assert_eq!(out, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+            assert_eq!(iter.next().await, None);
+        })
+    }
+}
@@ -0,0 +1,89 @@
+use crate::iter::ResultShunt;
+use crate::Iterator;
+use core::future::ready;
+
+/// Trait to represent types that can be created by summing up an iterator.
+pub trait Sum<A = Self>: Sized {
+    /// Takes an iterator and generates `Self` from the elements by "summing
+    /// up" the items.
+    async fn sum<I: Iterator<Item = A>>(iter: I) -> Self;
+}
+
+/// Trait to represent types that can be created by multiplying elements of
+/// an iterator.
+pub trait Product<A = Self>: Sized {
+    /// Takes an iterator and generates `Self` from the elements by
+    /// multiplying the items.
+    async fn product<I: Iterator<Item = A>>(iter: I) -> Self;
+}
+
+macro_rules! sum_product {
+    ($zero:expr, $one:expr; $($a:ty)*) => ($(
+        impl Sum for $a {
+            async fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($zero, |a, b| ready(a + b)).await
+            }
+        }
+
+        impl<'a> Sum<&'a $a> for $a {
+            async fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold($zero, |a, b| ready(a + b)).await
+            }
+        }
+
+        impl Product for $a {
+            async fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($one, |a, b| ready(a * b)).await
+            }
+        }
+
+        impl<'a> Product<&'a $a> for $a {
+            async fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold($one, |a, b| ready(a * b)).await
+            }
+        }
+    )*);
+}
+
+sum_product! { 0, 1; i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+sum_product! { 0.0, 1.0; f32 f64 }
+
+impl<T, U, E> Sum<Result<U, E>> for Result<T, E>
+where
+    T: Sum<U>,
+{
+    async fn sum<I: Iterator<Item = Result<U, E>>>(iter: I) -> Self {
+        let mut error = None;
+        let value = T::sum(ResultShunt::new(iter, &mut error)).await;
+        error.map_or(Ok(value), Err)
+    }
+}
+
+impl<T, U, E> Product<Result<U, E>> for Result<T, E>
+where
+    T: Product<U>,
+{
+    async fn product<I: Iterator<Item = Result<U, E>>>(iter: I) -> Self {
+        let mut error = None;
+        let value = T::product(ResultShunt::new(iter, &mut error)).await;
+        error.map_or(Ok(value), Err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    #[test]
+    fn sum_results() {
+        block_on(async {
+            let sum: Result<i32, &str> = from_iter(vec![Ok(1), Ok(2), Ok(3)]).sum().await;
+            assert_eq!(sum, Ok(6));
+
+            let iter = from_iter(vec![Ok(2), Err("boom"), Ok(3)]);
+            let product: Result<i32, &str> = iter.product().await;
+            assert_eq!(product, Err("boom"));
+        })
+    }
+}
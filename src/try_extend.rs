@@ -0,0 +1,124 @@
+use crate::IntoIterator;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::Iterator;
+
+/// Fallibly extend a collection with the contents of an iterator.
+pub trait TryExtend<A> {
+    /// The error returned when the collection can't hold another item.
+    type Error;
+
+    /// Extends a collection with the contents of an iterator, stopping at
+    /// the first item that can't be stored.
+    ///
+    /// Items stored before the error remain in the collection.
+    async fn try_extend<T>(&mut self, iter: T) -> Result<(), Self::Error>
+    where
+        T: IntoIterator<Item = A>;
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T> TryExtend<T> for std::vec::Vec<T> {
+    type Error = std::collections::TryReserveError;
+
+    async fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), Self::Error> {
+        let mut iter = iter.into_iter().await;
+        self.try_reserve(iter.size_hint().0)?;
+        while let Some(item) = iter.next().await {
+            if self.len() == self.capacity() {
+                self.try_reserve(1)?;
+            }
+            self.push(item);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T> TryExtend<T> for std::collections::VecDeque<T> {
+    type Error = std::collections::TryReserveError;
+
+    async fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), Self::Error> {
+        let mut iter = iter.into_iter().await;
+        self.try_reserve(iter.size_hint().0)?;
+        while let Some(item) = iter.next().await {
+            if self.len() == self.capacity() {
+                self.try_reserve(1)?;
+            }
+            self.push_back(item);
+        }
+        Ok(())
+    }
+}
+
+impl<A, E: TryExtend<A> + ?Sized> TryExtend<A> for &mut E {
+    type Error = E::Error;
+
+    async fn try_extend<I: IntoIterator<Item = A>>(&mut self, iter: I) -> Result<(), Self::Error> {
+        (**self).try_extend(iter).await
+    }
+}
+
+#[cfg(all(test, any(feature = "alloc", feature = "std")))]
+mod test {
+    use super::TryExtend;
+    use crate::test::block_on;
+    use crate::{from_iter, Iterator};
+
+    /// A fixed-capacity buffer, as found on embedded targets.
+    #[derive(Debug, Default)]
+    struct Bounded {
+        items: Vec<u8>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Overflow(u8);
+
+    impl TryExtend<u8> for Bounded {
+        type Error = Overflow;
+
+        async fn try_extend<I: crate::IntoIterator<Item = u8>>(
+            &mut self,
+            iter: I,
+        ) -> Result<(), Overflow> {
+            let mut iter = iter.into_iter().await;
+            while let Some(item) = iter.next().await {
+                if self.items.len() == 4 {
+                    return Err(Overflow(item));
+                }
+                self.items.push(item);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn retains_prior_contents() {
+        block_on(async {
+            let mut vec = vec![9];
+            from_iter(0..3).try_collect_into(&mut vec).await.unwrap();
+            assert_eq!(vec, vec![9, 0, 1, 2]);
+        })
+    }
+
+    #[test]
+    fn overflow() {
+        block_on(async {
+            let mut buf = Bounded::default();
+            let res = from_iter(0..6).try_collect_into(&mut buf).await;
+            assert_eq!(res.map(|_| ()), Err(Overflow(4)));
+            assert_eq!(buf.items, vec![0, 1, 2, 3]);
+        })
+    }
+
+    #[test]
+    fn allocation_failure() {
+        block_on(async {
+            let mut vec: Vec<u64> = vec![1];
+            // A lower bound this large can never be reserved.
+            let res = vec.try_extend(from_iter(0..u64::MAX)).await;
+            assert!(res.is_err());
+            assert_eq!(vec, vec![1]);
+        })
+    }
+}
@@ -0,0 +1,63 @@
+use core::ops::ControlFlow;
+
+/// A type which can either continue with an output value or short-circuit,
+/// such as `Result` and `Option`.
+///
+/// This is a stable stand-in for the standard library's unstable `Try`
+/// trait, used by [`Iterator::try_fold`](crate::Iterator::try_fold).
+pub trait Try: Sized {
+    /// The value produced when continuing.
+    type Output;
+
+    /// Wraps an output value, such as `Ok(output)` or `Some(output)`.
+    fn from_output(output: Self::Output) -> Self;
+
+    /// Decides whether to continue with the output value or to break with
+    /// `self`, such as for an `Err` or `None`.
+    fn branch(self) -> ControlFlow<Self, Self::Output>;
+}
+
+impl<T, E> Try for Result<T, E> {
+    type Output = T;
+
+    fn from_output(output: T) -> Self {
+        Ok(output)
+    }
+
+    fn branch(self) -> ControlFlow<Self, T> {
+        match self {
+            Ok(output) => ControlFlow::Continue(output),
+            Err(err) => ControlFlow::Break(Err(err)),
+        }
+    }
+}
+
+impl<T> Try for Option<T> {
+    type Output = T;
+
+    fn from_output(output: T) -> Self {
+        Some(output)
+    }
+
+    fn branch(self) -> ControlFlow<Self, T> {
+        match self {
+            Some(output) => ControlFlow::Continue(output),
+            None => ControlFlow::Break(None),
+        }
+    }
+}
+
+impl<B, C> Try for ControlFlow<B, C> {
+    type Output = C;
+
+    fn from_output(output: C) -> Self {
+        ControlFlow::Continue(output)
+    }
+
+    fn branch(self) -> ControlFlow<Self, C> {
+        match self {
+            ControlFlow::Continue(output) => ControlFlow::Continue(output),
+            ControlFlow::Break(b) => ControlFlow::Break(ControlFlow::Break(b)),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Checks that adapters store their closures' futures inline, rather than
+//! allocating one per item.
+
+#[path = "common/alloc_counter.rs"]
+mod alloc_counter;
+
+use alloc_counter::{allocations, block_on};
+use async_iterator::{from_iter, Iterator};
+use std::future::ready;
+
+// Everything runs in a single test so no other test thread can allocate
+// while the counter is being read.
+#[test]
+fn no_per_item_allocations() {
+    let map = allocations(|| {
+        block_on(
+            from_iter(0..1_000u64)
+                .map(|x| ready(x * 2))
+                .fold(0, |acc, x| ready(acc + x)),
+        )
+    });
+    assert_eq!(map, 0);
+
+    let map_async = allocations(|| {
+        block_on(
+            from_iter(0..1_000u64)
+                .map(|x| async move { x * 2 })
+                .filter(async |x: &u64| x.is_multiple_of(3))
+                .fold(0, |acc, x| ready(acc + x)),
+        )
+    });
+    assert_eq!(map_async, 0);
+
+    // One allocation for the in-flight set, and a driver and its item slot
+    // per inner iterator, however many items the inner iterator yields.
+    let flatten = allocations(|| {
+        block_on(
+            from_iter([from_iter(0..1_000u64)])
+                .flatten_unordered(1)
+                .fold(0, |acc, x| ready(acc + x)),
+        )
+    });
+    assert_eq!(flatten, 3);
+}